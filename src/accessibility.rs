@@ -0,0 +1,118 @@
+use leptos::*;
+
+use crate::{TitleOptions, TooltipData};
+
+/// Text alternatives of a chart for screen readers.
+///
+/// Every chart is labelled with its title and a description, and its bars, segments or
+/// points with their label and formatted value.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessibilityOptions {
+    /// name of the chart, its title if not set
+    pub label: Option<String>,
+    /// read after the name, a short summary of the data if not set
+    pub description: Option<String>,
+    /// adds a table of all values that is only visible to screen readers
+    pub data_table: bool,
+}
+
+impl AccessibilityOptions {
+    /// Name of a chart with `titles`, `fallback` (e.g. "Bar chart") if it has none
+    pub(crate) fn label_for(&self, titles: &TitleOptions, fallback: &str) -> String {
+        self.label
+            .clone()
+            .or_else(|| titles.title.clone())
+            .unwrap_or_else(|| fallback.to_string())
+    }
+
+    /// Description of a chart, `summary` unless one is set
+    pub(crate) fn description_or(&self, summary: impl Fn() -> String + 'static) -> Signal<String> {
+        match self.description.clone() {
+            Some(description) => Signal::derive(move || description.clone()),
+            None => Signal::derive(summary),
+        }
+    }
+}
+
+impl TooltipData {
+    /// Label of the value for screen readers, e.g. "Sales, March: 1.2k"
+    pub(crate) fn aria_label(&self) -> String {
+        match &self.x {
+            Some(x) => format!("{}, {}: {}", self.name, x, self.value),
+            None => format!("{}: {}", self.name, self.value),
+        }
+    }
+}
+
+/// `count` with the `singular` or `plural` noun, e.g. "3 bars"
+pub(crate) fn count(count: usize, singular: &str, plural: &str) -> String {
+    format!("{} {}", count, if count == 1 { singular } else { plural })
+}
+
+/// Table of the values of a chart, only visible to screen readers.
+///
+/// `headers` are the headers of the name, x and value columns of `rows`. The x column is
+/// left out if no row has an x value.
+#[component]
+pub(crate) fn DataTable(
+    caption: String,
+    #[prop(into)] headers: MaybeSignal<[&'static str; 3]>,
+    #[prop(into)] rows: Signal<Vec<TooltipData>>,
+) -> impl IntoView {
+    let has_x = move || rows.with(|r| r.iter().any(|r| r.x.is_some()));
+    view! {
+        <foreignObject x="0" y="0" width="1" height="1" overflow="hidden">
+            <table style="position: absolute; width: 1px; height: 1px; overflow: hidden; clip-path: inset(50%); white-space: nowrap">
+                <caption>{caption}</caption>
+                <thead>
+                    <tr>
+                        <th scope="col">{move || headers.get()[0]}</th>
+                        {move || has_x().then(|| view! { <th scope="col">{headers.get()[1]}</th> })}
+                        <th scope="col">{move || headers.get()[2]}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {move || {
+                        let has_x = has_x();
+                        rows.get()
+                            .into_iter()
+                            .map(|row| {
+                                view! {
+                                    <tr>
+                                        <th scope="row">{row.name}</th>
+                                        {has_x.then(|| view! { <td>{row.x}</td> })}
+                                        <td>{row.value}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()
+                    }}
+
+                </tbody>
+            </table>
+        </foreignObject>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels() {
+        let data = TooltipData {
+            name: "Sales".to_string(),
+            x: Some("March".to_string()),
+            value: "1.2k".to_string(),
+            color: "#000".to_string(),
+        };
+        assert_eq!(data.aria_label(), "Sales, March: 1.2k");
+        let options = AccessibilityOptions::default();
+        assert_eq!(
+            options.label_for(&TitleOptions::default(), "Pie chart"),
+            "Pie chart"
+        );
+        assert_eq!(count(1, "series", "series"), "1 series");
+        assert_eq!(count(3, "bar", "bars"), "3 bars");
+    }
+}
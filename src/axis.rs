@@ -1,21 +1,123 @@
+use crate::{
+    format::{Plain, TickFormat},
+    layout::{LayoutOptions, PlotArea},
+    utils::{Domain, Scale},
+};
 use leptos::component;
-use leptos::leptos_dom::logging::console_log;
 use leptos::{svg::*, *};
 
+/// Side of the plot a vertical axis is drawn on
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AxisSide {
+    #[default]
+    Left,
+    Right,
+}
+
+/// Second value axis on the right of the plot, for series in a different unit than the
+/// others (e.g. an error rate next to request counts)
+pub struct SecondaryAxis {
+    /// names of the series that are drawn against this axis
+    pub series: Vec<String>,
+    /// scale of the axis, falls back to a linear scale like the primary axis
+    pub scale: Scale,
+    pub domain: Domain,
+    /// labels of the axis and of hovered values of its series
+    pub format: Box<dyn TickFormat>,
+}
+
+impl Default for SecondaryAxis {
+    fn default() -> Self {
+        Self {
+            series: vec![],
+            scale: Scale::default(),
+            domain: Domain::default(),
+            format: Box::new(Plain),
+        }
+    }
+}
+
+impl SecondaryAxis {
+    /// Whether the series called `name` is drawn against this axis
+    pub fn contains(&self, name: &str) -> bool {
+        self.series.iter().any(|s| s == name)
+    }
+}
+
+/// Scales the font size of a tick label down from `font_size` px so that it fits into
+/// `target_width` px
+fn shrink_text(elem: &HtmlElement<Text>, font_size: f64, target_width: f64) {
+    // measure at the full size, the label might have been shrunk for a smaller chart before
+    elem.set_attribute("font-size", format!("{}px", font_size).as_str())
+        .expect("the fontsize to be changed");
+    let text_width = elem.get_bounding_client_rect().width();
+    if text_width <= target_width {
+        return;
+    }
+    let font_size = font_size * target_width / text_width;
+    elem.set_attribute("font-size", format!("{:.2?}px", font_size).as_str())
+        .expect("the fontsize to be changed");
+}
+
+/// Vertical axis left (or right, depending on `side`) of the plot area.
+///
+/// `ticks` are positions in percent of the plot height (from the top) and their labels.
+/// Tick lengths and label sizes are taken from `layout`, labels wider than the space
+/// [`LayoutOptions::labels_width`] reserves for them are shrunk.
 #[component]
-pub fn YAxis(ticks: Memo<Vec<(f64, String)>>) -> impl IntoView {
-    let svg_ref = create_node_ref::<Svg>();
+pub fn YAxis(
+    ticks: Memo<Vec<(f64, String)>>,
+    /// positions of unlabeled minor ticks, in percent from the top
+    #[prop(optional, into)]
+    minor_ticks: MaybeSignal<Vec<f64>>,
+    #[prop(into)] area: Signal<PlotArea>,
+    /// size of the chart in px
+    #[prop(into)]
+    size: Signal<(f64, f64)>,
+    #[prop(optional)] layout: LayoutOptions,
+    #[prop(optional)] side: AxisSide,
+) -> impl IntoView {
+    // position `px` away from the plot, in percent of the chart
+    let outside = move |px: f64| {
+        let distance = 100.0 * px / size.get().0;
+        match side {
+            AxisSide::Left => format!("{}%", area.get().x - distance),
+            AxisSide::Right => format!("{}%", area.get().right() + distance),
+        }
+    };
+    // the axis line is drawn on the edge of the plot
+    let axis_x = move || outside(0.0);
     view! {
-        <svg y="0%" height="100%" _ref=svg_ref>
+        <svg y="0%" height="100%">
             <line
-                x1="9.8%"
-                y1="10%"
-                x2="9.8%"
-                y2="90%"
+                x1=axis_x
+                y1=move || format!("{}%", area.get().y)
+                x2=axis_x
+                y2=move || format!("{}%", area.get().bottom())
                 stroke="black"
                 stroke-width="1px"
                 vector-effect="non-scaling-stroke"
             ></line>
+            {move || {
+                minor_ticks
+                    .get()
+                    .into_iter()
+                    .map(|t| {
+                        let t = move || format!("{}%", area.get().y_at(t));
+                        view! {
+                            <line
+                                x1=move || outside(layout.tick_length / 2.0)
+                                y1=t
+                                x2=axis_x
+                                y2=t
+                                stroke="black"
+                                stroke-width="1px"
+                                vector-effect="non-scaling-stroke"
+                            ></line>
+                        }
+                    })
+                    .collect_view()
+            }}
             {move || {
                 ticks
                     .get()
@@ -23,50 +125,142 @@ pub fn YAxis(ticks: Memo<Vec<(f64, String)>>) -> impl IntoView {
                     .map(|(t, s)| {
                         let node_ref = create_node_ref::<Text>();
                         create_effect(move |_| {
-                            if let Some(parent) = svg_ref.get() {
-                                if let Some(elem) = node_ref.get() {
-                                    request_animation_frame(move || {
-                                        let parent_width = parent
-                                            .get_bounding_client_rect()
-                                            .width();
-                                        let target_width = parent_width * 0.069;
-                                        let text_size = elem.get_bounding_client_rect();
-                                        if text_size.width() > target_width {
-                                            let factor = target_width / text_size.width();
-                                            console_log(format!("{:?}", factor).as_str());
-                                            elem.set_attribute(
-                                                    "font-size",
-                                                    format!("{:.2?}em", factor).as_str(),
-                                                )
-                                                .expect("the fontsize to be changed");
-                                            elem.set_attribute(
-                                                    "dy",
-                                                    format!("{:.2?}em", factor / 2.0).as_str(),
-                                                )
-                                                .expect("to set dy");
-                                        }
-                                    });
+                            if let Some(elem) = node_ref.get() {
+                                let target_width = ticks
+                                    .with(|ticks| layout.labels_width(ticks, size.get()));
+                                request_animation_frame(move || {
+                                    shrink_text(&elem, layout.font_size, target_width);
+                                });
+                            }
+                        });
+                        let t = move || format!("{}%", area.get().y_at(t));
+                        view! {
+                            <line
+                                x1=move || outside(layout.tick_length)
+                                y1=t
+                                x2=axis_x
+                                y2=t
+                                stroke="black"
+                                stroke-width="1px"
+                                vector-effect="non-scaling-stroke"
+                            ></line>
+                            <text
+                                _ref=node_ref
+                                x=move || outside(layout.label_offset())
+                                y=t
+                                font-size=format!("{}px", layout.font_size)
+                                dominant-baseline="central"
+                                text-anchor=match side {
+                                    AxisSide::Left => "end",
+                                    AxisSide::Right => "start",
+                                }
+
+                                vector-effect="non-scaling-stroke"
+                                lengthAdjust="spacing"
+                            >
+                                {s}
+                            </text>
+                        }
+                    })
+                    .collect_view()
+            }}
+
+        </svg>
+    }
+}
+
+/// Horizontal axis below the plot area.
+///
+/// `ticks` are positions in percent of the plot width (from the left) and their labels,
+/// so this works both for numeric ticks from [`crate::utils::get_x_ticks`] and for
+/// category labels at band centers from [`crate::utils::get_band_ticks`].
+#[component]
+pub fn XAxis(
+    ticks: Memo<Vec<(f64, String)>>,
+    /// positions of unlabeled minor ticks, in percent from the left
+    #[prop(optional, into)]
+    minor_ticks: MaybeSignal<Vec<f64>>,
+    #[prop(into)] area: Signal<PlotArea>,
+    /// size of the chart in px
+    #[prop(into)]
+    size: Signal<(f64, f64)>,
+    #[prop(optional)] layout: LayoutOptions,
+) -> impl IntoView {
+    // vertical distance in px, in percent of the chart
+    let px = move |px: f64| 100.0 * px / size.get().1;
+    // the axis line is drawn on the edge of the plot
+    let axis_y = move || format!("{}%", area.get().bottom());
+    view! {
+        <svg x="0%" width="100%">
+            <line
+                x1=move || format!("{}%", area.get().x)
+                y1=axis_y
+                x2=move || format!("{}%", area.get().right())
+                y2=axis_y
+                stroke="black"
+                stroke-width="1px"
+                vector-effect="non-scaling-stroke"
+            ></line>
+            {move || {
+                minor_ticks
+                    .get()
+                    .into_iter()
+                    .map(|t| {
+                        let t = move || format!("{}%", area.get().x_at(t));
+                        view! {
+                            <line
+                                x1=t
+                                y1=axis_y
+                                x2=t
+                                y2=move || {
+                                    format!("{}%", area.get().bottom() + px(layout.tick_length / 2.0))
                                 }
+
+                                stroke="black"
+                                stroke-width="1px"
+                                vector-effect="non-scaling-stroke"
+                            ></line>
+                        }
+                    })
+                    .collect_view()
+            }}
+            {move || {
+                let ticks = ticks.get();
+                let num_ticks = ticks.len().max(1);
+                ticks
+                    .into_iter()
+                    .map(|(t, s)| {
+                        let node_ref = create_node_ref::<Text>();
+                        create_effect(move |_| {
+                            if let Some(elem) = node_ref.get() {
+                                let target_width = size.get().0 * area.get().width / 100.0
+                                    / num_ticks as f64;
+                                request_animation_frame(move || {
+                                    shrink_text(&elem, layout.font_size, target_width * 0.9);
+                                });
                             }
                         });
-                        let t = 10.0 + t * 0.8;
+                        let t = move || format!("{}%", area.get().x_at(t));
                         view! {
                             <line
-                                x1="7%"
-                                y1=format!("{}%", t)
-                                x2="9.8%"
-                                y2=format!("{}%", t)
+                                x1=t
+                                y1=axis_y
+                                x2=t
+                                y2=move || format!("{}%", area.get().bottom() + px(layout.tick_length))
                                 stroke="black"
-                                strocke-width="1px"
+                                stroke-width="1px"
                                 vector-effect="non-scaling-stroke"
                             ></line>
                             <text
                                 _ref=node_ref
-                                x="6.9%"
-                                y=format!("{}%", t)
-                                font-size="1em"
-                                dy="0.5em"
-                                text-anchor="end"
+                                x=t
+                                y=move || {
+                                    format!("{}%", area.get().bottom() + px(layout.label_offset()))
+                                }
+
+                                font-size=format!("{}px", layout.font_size)
+                                dominant-baseline="hanging"
+                                text-anchor="middle"
                                 vector-effect="non-scaling-stroke"
                                 lengthAdjust="spacing"
                             >
@@ -1,11 +1,81 @@
-use crate::{axis::YAxis, utils, ChartColor, Palette, CATPPUCCIN_COLORS};
+use std::rc::Rc;
+
+use crate::{
+    accessibility::{count, AccessibilityOptions, DataTable},
+    axis::{AxisSide, SecondaryAxis, XAxis, YAxis},
+    brush::{use_brush, Brush, BrushArea, BrushMode, BrushOptions, Selection},
+    focus::{center_in, is_focus_visible, FocusNavigation},
+    format::{Locale, Plain, TickFormat},
+    grid::{Grid, GridOptions},
+    item::{ChartItem, ItemEvents},
+    layout::{use_chart_size, LayoutOptions, PlotArea},
+    legend::{Legend, LegendOptions},
+    point::NamedSeries,
+    title::{TitleOptions, Titles},
+    tooltip::{show_on_hover, use_pointer, Tooltip, TooltipData, TooltipOptions},
+    utils::{self, Domain, Scale},
+    ChartColor, Palette, CATPPUCCIN_COLORS,
+};
 use leptos::{svg::*, *};
 use leptos_use::*;
 use num_traits::ToPrimitive;
 
+/// How multiple series are arranged within a category
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BarMode {
+    /// bars of the series are drawn side by side
+    #[default]
+    Grouped,
+    /// bars of the series are stacked on top of each other, negative values below zero
+    Stacked,
+    /// like `Stacked`, but every category is scaled to 100%
+    StackedPercent,
+}
+
+/// Direction the bars grow in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BarOrientation {
+    /// bars grow upwards, categories are along the bottom
+    #[default]
+    Vertical,
+    /// bars grow to the right, categories are along the left
+    Horizontal,
+}
+
 pub struct BarChartOptions {
     pub max_ticks: u8,
     pub color: Box<dyn ChartColor>,
+    /// shows a legend if set, with the bar `labels` for a single series and the
+    /// series names for multiple series
+    pub legend: Option<LegendOptions>,
+    pub mode: BarMode,
+    pub orientation: BarOrientation,
+    /// scale of the value axis, logarithmic scales fall back to linear ones if there are
+    /// values <= 0. Bars on logarithmic scales start at the bottom of the axis
+    pub scale: Scale,
+    /// range of the value axis, includes zero by default
+    pub domain: Domain,
+    /// draws gridlines behind the bars if set
+    pub grid: Option<GridOptions>,
+    /// adds unlabeled ticks between the ticks of a linear value axis, logarithmic axes
+    /// always have them
+    pub minor_ticks: bool,
+    /// labels of the value axis and of hovered bars
+    pub format: Box<dyn TickFormat>,
+    pub locale: Locale,
+    pub titles: TitleOptions,
+    /// margins around the plot and sizes of the axes
+    pub layout: LayoutOptions,
+    /// axis on the right for the series it names, e.g. for a metric in another unit. Only
+    /// used for vertical bars, series on it are stacked separately from the others
+    pub secondary: Option<SecondaryAxis>,
+    /// shows details of hovered bars if set
+    pub tooltip: Option<TooltipOptions>,
+    /// selects bars by dragging across categories if set, only used for vertical bars
+    pub brush: Option<BrushOptions>,
+    /// selects a bar when it's clicked and highlights it until it's clicked again
+    pub selectable: bool,
+    pub accessibility: AccessibilityOptions,
 }
 
 impl Default for BarChartOptions {
@@ -13,12 +83,50 @@ impl Default for BarChartOptions {
         Self {
             max_ticks: 5u8,
             color: Box::new(Palette(CATPPUCCIN_COLORS.clone())),
+            legend: None,
+            mode: BarMode::default(),
+            orientation: BarOrientation::default(),
+            scale: Scale::default(),
+            domain: Domain {
+                include_zero: true,
+                ..Default::default()
+            },
+            grid: None,
+            minor_ticks: false,
+            format: Box::new(Plain),
+            locale: Locale::default(),
+            titles: TitleOptions::default(),
+            layout: LayoutOptions::default(),
+            secondary: None,
+            tooltip: Some(TooltipOptions::default()),
+            brush: None,
+            selectable: false,
+            accessibility: AccessibilityOptions::default(),
         }
     }
 }
 
+/// A single bar, positioned in percent of the plot along the category axis and in data
+/// units along the value axis
+#[derive(Clone, Debug, PartialEq)]
+struct Bar {
+    value: f64,
+    span: (f64, f64),
+    band_start: f64,
+    band_width: f64,
+    color_index: usize,
+    series: usize,
+    category: usize,
+    /// drawn against the secondary axis
+    secondary: bool,
+}
+
 /// Simple responsive bar chart
 ///
+/// Takes either a single series in `values`, where every bar gets its own color, or
+/// multiple series in `series`, where every series gets its own color and is arranged
+/// according to [`BarChartOptions::mode`].
+///
 /// Example:
 /// ```rust
 /// use leptos_charts::*;
@@ -28,6 +136,7 @@ impl Default for BarChartOptions {
 /// let options = Box::new(BarChartOptions {
 ///     max_ticks: 4,
 ///     color: Box::new(Palette(CATPPUCCIN_COLORS.clone())),
+///     ..Default::default()
 /// });
 /// # #[cfg(hydrate)]
 /// # {
@@ -44,121 +153,634 @@ impl Default for BarChartOptions {
 /// # }
 /// # ;
 /// ```
+///
+/// Stacked series:
+/// ```rust
+/// use leptos_charts::*;
+/// use leptos::*;
+///
+/// let data = vec![
+///     NamedSeries::new("2022", vec![2, 3, -1]),
+///     NamedSeries::new("2023", vec![4, 1, -2]),
+/// ];
+/// let options = Box::new(BarChartOptions {
+///     mode: BarMode::Stacked,
+///     legend: Some(LegendOptions::default()),
+///     ..Default::default()
+/// });
+/// # #[cfg(hydrate)]
+/// # {
+/// view!{
+/// <BarChart
+///     series=data.into()
+///     labels=vec!["north".to_string(), "south".to_string(), "west".to_string()]
+///     options=options
+///     attr:width="300"
+///     attr:height="200"
+/// />
+/// }
+/// # }
+/// # ;
+/// ```
+#[allow(clippy::boxed_local)]
 #[component]
 pub fn BarChart<T>(
+    /// values of a single series, for multiple series use `series`
+    #[prop(optional)]
     values: MaybeSignal<Vec<T>>,
+    /// multiple named series with one value per category, drawn after `values`
+    #[prop(optional)]
+    series: MaybeSignal<Vec<NamedSeries<T>>>,
     options: Box<BarChartOptions>,
+    /// labels of the categories, in the same order as the values
+    #[prop(optional, into)]
+    labels: MaybeSignal<Vec<String>>,
+    /// bars selected with the brush, `None` if there is no selection
+    #[prop(optional)]
+    selection: Option<RwSignal<Option<Selection>>>,
+    /// called with the bar that is clicked
+    #[prop(optional, into)]
+    on_click: Option<Callback<ChartItem>>,
+    /// the selected bar, if `selectable` is set in the options
+    #[prop(optional)]
+    selected: Option<RwSignal<Option<ChartItem>>>,
     #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView
 where
     T: ToPrimitive + Clone + PartialOrd + 'static,
 {
-    let vals = values.clone();
-    let num_bars = create_memo(move |_| vals.get().len());
-    let vals = values.clone();
-    let min_max = create_memo(move |_| vals.with(utils::get_min_max));
-    let values = create_memo(move |_| {
-        values
-            .get()
+    let BarChartOptions {
+        max_ticks,
+        color,
+        legend,
+        mode,
+        orientation,
+        scale,
+        domain,
+        grid,
+        minor_ticks: show_minor_ticks,
+        format,
+        locale,
+        titles,
+        layout,
+        secondary,
+        tooltip,
+        brush,
+        selectable,
+        accessibility,
+    } = *options;
+    let events = ItemEvents::new(on_click, selected, selectable);
+    let horizontal = orientation == BarOrientation::Horizontal;
+    let brush = brush.filter(|_| !horizontal);
+    let selection = selection.unwrap_or_else(|| create_rw_signal(None));
+    let color: Rc<dyn ChartColor> = color.into();
+    let secondary = secondary.filter(|_| !horizontal).map(Rc::new);
+    let tooltip = tooltip.map(Rc::new);
+    let hovered = create_rw_signal(None::<TooltipData>);
+    let format: Rc<dyn TickFormat> = format.into();
+    let labels = Signal::derive(move || labels.get());
+    let series = create_memo(move |_| {
+        let single = values.get();
+        (!single.is_empty())
+            .then(|| NamedSeries::new("", single))
             .into_iter()
-            .map(|v| v.to_f64().unwrap())
-            .enumerate()
-            .collect::<Vec<(usize, f64)>>()
+            .chain(series.get())
+            .map(|s| NamedSeries {
+                name: s.name,
+                values: s.values.into_iter().map(|v| v.to_f64().unwrap()).collect(),
+            })
+            .collect::<Vec<NamedSeries<f64>>>()
+    });
+    let single_series = create_memo(move |_| series.with(|s| s.len() == 1));
+    let num_categories =
+        create_memo(move |_| series.with(|s| s.iter().map(|s| s.values.len()).max().unwrap_or(0)));
+    let legend_labels = Signal::derive(move || {
+        if single_series.get() {
+            labels.get()
+        } else {
+            series.with(|s| s.iter().map(|s| s.name.clone()).collect())
+        }
+    });
+    let num_colors = create_memo(move |_| {
+        if single_series.get() {
+            series.with(|s| s[0].values.len())
+        } else {
+            series.with(Vec::len)
+        }
+    });
+    let bars = create_memo({
+        let secondary = secondary.clone();
+        move |_| {
+            series.with(|series| {
+                let values = series
+                    .iter()
+                    .map(|s| s.values.clone())
+                    .collect::<Vec<Vec<f64>>>();
+                let on_secondary = series
+                    .iter()
+                    .map(|s| secondary.as_ref().is_some_and(|a| a.contains(&s.name)))
+                    .collect::<Vec<bool>>();
+                let num_categories = values.iter().map(Vec::len).max().unwrap_or(0);
+                let spans = match mode {
+                    BarMode::Grouped => values
+                        .iter()
+                        .map(|s| s.iter().map(|v| (0.0, *v)).collect())
+                        .collect(),
+                    BarMode::Stacked => utils::stack_series_per_axis(&values, &on_secondary, false),
+                    BarMode::StackedPercent => {
+                        utils::stack_series_per_axis(&values, &on_secondary, true)
+                    }
+                };
+                let single = values.len() == 1;
+                let group_size = match mode {
+                    BarMode::Grouped => values.len().max(1),
+                    BarMode::Stacked | BarMode::StackedPercent => 1,
+                };
+                values
+                    .iter()
+                    .zip(spans)
+                    .enumerate()
+                    .flat_map(|(j, (values, spans))| {
+                        let secondary = on_secondary[j];
+                        values
+                            .iter()
+                            .zip(spans)
+                            .enumerate()
+                            .map(move |(c, (value, span))| {
+                                let (start, width) = utils::band(c, num_categories);
+                                let width = width / group_size as f64;
+                                Bar {
+                                    value: *value,
+                                    span,
+                                    band_start: start + width * (j % group_size) as f64,
+                                    band_width: width,
+                                    color_index: if single { c } else { j },
+                                    series: j,
+                                    category: c,
+                                    secondary,
+                                }
+                            })
+                    })
+                    .collect::<Vec<Bar>>()
+            })
+        }
+    });
+    // what the tooltip shows and screen readers read for every bar, in the order of `bars`
+    let bar_data = create_memo({
+        let color = color.clone();
+        let secondary = secondary.clone();
+        let format = format.clone();
+        move |_| {
+            bars.with(|bars| {
+                bars.iter()
+                    .map(|bar| {
+                        let value = match secondary.as_ref().filter(|_| bar.secondary) {
+                            Some(axis) => axis.format.format_localized(bar.value, locale),
+                            None => format.format_localized(bar.value, locale),
+                        };
+                        let category = labels.with(|l| l.get(bar.category).cloned());
+                        let (name, x) = if single_series.get() {
+                            (category.unwrap_or_default(), None)
+                        } else {
+                            (series.with(|s| s[bar.series].name.clone()), category)
+                        };
+                        TooltipData {
+                            name,
+                            x,
+                            value,
+                            color: String::from(
+                                color.color_for_index(bar.color_index, num_colors.get()),
+                            ),
+                        }
+                    })
+                    .collect::<Vec<TooltipData>>()
+            })
+        }
+    });
+    let label = accessibility.label_for(&titles, "Bar chart");
+    let description = accessibility.description_or(move || {
+        if single_series.get() {
+            count(num_categories.get(), "bar", "bars")
+        } else {
+            format!(
+                "{} of {}: {}",
+                count(series.with(Vec::len), "series", "series"),
+                count(num_categories.get(), "category", "categories"),
+                legend_labels.get().join(", ")
+            )
+        }
+    });
+    let svg_ref = create_node_ref::<Svg>();
+    let size = use_chart_size(svg_ref);
+    let pointer = use_pointer(svg_ref);
+    let focus = FocusNavigation::new(Signal::derive(move || bars.with(Vec::len)));
+    // center of the focused bar in px, the tooltip is shown there instead of at the pointer
+    let focus_anchor = create_rw_signal(None::<(f64, f64)>);
+    let tooltip_position =
+        Signal::derive(move || focus_anchor.get().unwrap_or_else(|| pointer.get()));
+    let title_region = create_memo({
+        let titles = titles.clone();
+        move |_| titles.remaining_area(PlotArea::FULL, size.get())
+    });
+    let axes_region = create_memo(move |_| {
+        let region = title_region.get();
+        legend
+            .map(|legend| legend_labels.with(|l| legend.remaining_area(l, region, size.get())))
+            .unwrap_or(region)
+    });
+    // ticks of the primary or secondary value axis, from the bars drawn against it
+    let axis_ticks = move |secondary: bool, scale: Scale, domain: &Domain| {
+        bars.with(|bars| {
+            let bars = bars.iter().filter(|b| b.secondary == secondary);
+            let values = bars.clone().map(|b| b.value).collect::<Vec<f64>>();
+            let scale = utils::checked_scale(scale, &values);
+            // every bar starts at zero or at the end of another bar
+            let ends = bars.map(|b| b.span.1).collect::<Vec<f64>>();
+            utils::scale_ticks(scale, domain, &ends, max_ticks)
+        })
+    };
+    let tick_config = create_memo(move |_| axis_ticks(false, scale, &domain));
+    let secondary_tick_config = create_memo({
+        let secondary = secondary.clone();
+        move |_| {
+            secondary
+                .as_ref()
+                .map(|axis| axis_ticks(true, axis.scale, &axis.domain))
+        }
+    });
+    let secondary_ticks = create_memo({
+        let secondary = secondary.clone();
+        move |_| {
+            secondary_tick_config.with(|t| match (t, secondary.as_ref()) {
+                (Some(t), Some(axis)) => utils::get_ticks(t, axis.format.as_ref(), locale),
+                _ => vec![],
+            })
+        }
+    });
+    let minor_ticks = move |linear: bool| {
+        tick_config.with(|t| {
+            if horizontal {
+                utils::get_x_minor_ticks(t, linear)
+            } else {
+                utils::get_minor_ticks(t, linear)
+            }
+        })
+    };
+    let band_ticks =
+        create_memo(move |_| labels.with(|l| utils::get_band_ticks(l, num_categories.get())));
+    let y_ticks = create_memo({
+        let format = format.clone();
+        move |_| {
+            if horizontal {
+                band_ticks.get()
+            } else {
+                tick_config.with(|t| utils::get_ticks(t, format.as_ref(), locale))
+            }
+        }
+    });
+    let x_ticks = create_memo({
+        let format = format.clone();
+        move |_| {
+            if horizontal {
+                tick_config.with(|t| utils::get_x_ticks(t, format.as_ref(), locale))
+            } else {
+                band_ticks.get()
+            }
+        }
+    });
+    let plot_area = create_memo({
+        let titles = titles.clone();
+        move |_| {
+            let region = titles.axes_area(axes_region.get(), size.get());
+            y_ticks.with(|y| {
+                x_ticks.with(|x| {
+                    secondary_ticks
+                        .with(|secondary| layout.plot_area(region, size.get(), y, x, secondary))
+                })
+            })
+        }
+    });
+    // position along the value axis of a bar, in percent of the plot
+    let value_pos = move |v: f64, secondary: bool| {
+        secondary_tick_config
+            .with(|t| t.as_ref().filter(|_| secondary).map(|t| t.position(v)))
+            .unwrap_or_else(|| tick_config.with(|t| t.position(v)))
+    };
+    let dragged = brush.as_ref().map(|brush| {
+        let mode = brush.mode;
+        use_brush(svg_ref, plot_area.into(), mode, move |area| {
+            let selected = area.map(|area| {
+                let n = num_categories.get_untracked();
+                let indices = bars.with_untracked(|bars| {
+                    bars.iter()
+                        .filter(|bar| {
+                            let center = bar.band_start + bar.band_width / 2.0;
+                            let (bottom, top) = (
+                                value_pos(bar.span.0.min(bar.span.1), bar.secondary),
+                                value_pos(bar.span.0.max(bar.span.1), bar.secondary),
+                            );
+                            (area.x.0..=area.x.1).contains(&center)
+                                && bottom <= 100.0 - area.y.0
+                                && top >= 100.0 - area.y.1
+                        })
+                        .map(|bar| (bar.series, bar.category))
+                        .collect()
+                });
+                Selection {
+                    x: (
+                        utils::band_units(area.x.0, n),
+                        utils::band_units(area.x.1, n),
+                    ),
+                    y: (mode == BrushMode::XY).then(|| {
+                        tick_config.with_untracked(|t| {
+                            (t.value_at(100.0 - area.y.1), t.value_at(100.0 - area.y.0))
+                        })
+                    }),
+                    indices,
+                }
+            });
+            selection.set(selected);
+        })
+    });
+    // selection in percent of the plot, while dragging or after
+    let selected_area = Signal::derive(move || {
+        dragged.and_then(|dragged| dragged.get()).or_else(|| {
+            selection.get().map(|s| {
+                let n = num_categories.get();
+                BrushArea {
+                    x: (
+                        utils::band_position(s.x.0, n),
+                        utils::band_position(s.x.1, n),
+                    ),
+                    y: s.y
+                        .map(|(min, max)| {
+                            tick_config.with(|t| (100.0 - t.position(max), 100.0 - t.position(min)))
+                        })
+                        .unwrap_or((0.0, 100.0)),
+                }
+            })
+        })
     });
-    let max_ticks = options.max_ticks;
-    let tick_config =
-        create_memo(move |_| utils::nice_ticks(min_max.get().0, min_max.get().1, max_ticks));
-    let ticks = create_memo(move |_| tick_config.with(utils::get_ticks));
 
     view! {
-        <svg {..attrs}>
-            <YAxis ticks=ticks/>
+        <svg role="figure" {..attrs} _ref=svg_ref>
+            <title>{label.clone()}</title>
+            <desc>{description}</desc>
+            {grid
+                .map(|grid| {
+                    view! {
+                        <Grid
+                            // lines of the category axis run between the bars, not through them
+                            horizontal=Signal::derive(move || {
+                                if horizontal {
+                                    utils::band_boundaries(num_categories.get())
+                                } else {
+                                    y_ticks.with(|t| t.iter().map(|t| t.0).collect())
+                                }
+                            })
+
+                            vertical=Signal::derive(move || {
+                                if horizontal {
+                                    x_ticks.with(|t| t.iter().map(|t| t.0).collect())
+                                } else {
+                                    utils::band_boundaries(num_categories.get())
+                                }
+                            })
+
+                            horizontal_minor=Signal::derive(move || {
+                                if horizontal { vec![] } else { minor_ticks(true) }
+                            })
+
+                            vertical_minor=Signal::derive(move || {
+                                if horizontal { minor_ticks(true) } else { vec![] }
+                            })
+
+                            options=grid
+                            area=plot_area
+                        />
+                    }
+                })}
+            <YAxis
+                ticks=y_ticks
+                minor_ticks=Signal::derive(move || {
+                    if horizontal { vec![] } else { minor_ticks(show_minor_ticks) }
+                })
+
+                area=plot_area
+                size=size
+                layout=layout
+            />
+            {secondary
+                .is_some()
+                .then(|| {
+                    view! {
+                        <YAxis
+                            ticks=secondary_ticks
+                            minor_ticks=Signal::derive(move || {
+                                secondary_tick_config
+                                    .with(|t| {
+                                        t.as_ref()
+                                            .map(|t| utils::get_minor_ticks(t, show_minor_ticks))
+                                            .unwrap_or_default()
+                                    })
+                            })
+
+                            area=plot_area
+                            size=size
+                            layout=layout
+                            side=AxisSide::Right
+                        />
+                    }
+                })}
+            <XAxis
+                ticks=x_ticks
+                minor_ticks=Signal::derive(move || {
+                    if horizontal { minor_ticks(show_minor_ticks) } else { vec![] }
+                })
+
+                area=plot_area
+                size=size
+                layout=layout
+            />
+            <Titles
+                options=titles
+                region=title_region
+                axes_region=axes_region
+                plot=plot_area
+                size=size
+            />
+            {legend
+                .map(|legend| {
+                    view! {
+                        <Legend
+                            labels=legend_labels
+                            color=color.clone()
+                            options=legend
+                            region=move || legend.region(title_region.get(), plot_area.get())
+                            size=size
+                        />
+                    }
+                })}
 
             {move || {
-                values
-                    .get()
+                bars.get()
                     .into_iter()
-                    .map(|(i, v)| {
+                    .zip(bar_data.get())
+                    .enumerate()
+                    .map(|(k, (bar, data))| {
                         let el = create_node_ref::<Rect>();
                         let is_hovered = use_element_hover(el);
-                        let color = String::from(options.color.color_for_index(i, num_bars.get()));
+                        let is_active =
+                            Signal::derive(move || is_hovered.get() || focus.is_focused(k));
+                        focus
+                            .register(
+                                k,
+                                move || {
+                                    if let Some(el) = el.get_untracked() {
+                                        let _ = el.focus();
+                                    }
+                                },
+                            );
+                        let color = data.color.clone();
+                        let (bottom, top) = if bar.span.0 < bar.span.1 {
+                            bar.span
+                        } else {
+                            (bar.span.1, bar.span.0)
+                        };
+                        let item = ChartItem {
+                            series: bar.series,
+                            index: bar.category,
+                            value: bar.value,
+                            label: labels.with(|l| l.get(bar.category).cloned()).unwrap_or_default(),
+                        };
+                        let is_selected = move || events.is_selected(bar.series, bar.category);
+                        let aria_label = data.aria_label();
+                        show_on_hover(is_active, data, hovered);
+                        let length = move || {
+                            value_pos(top, bar.secondary) - value_pos(bottom, bar.secondary)
+                        };
                         view! {
                             <svg
-                                x="10%"
-                                y="10%"
-                                width="90%"
-                                height="80%"
+                                x=move || format!("{}%", plot_area.get().x)
+                                y=move || format!("{}%", plot_area.get().y)
+                                width=move || format!("{}%", plot_area.get().width)
+                                height=move || format!("{}%", plot_area.get().height)
                                 viewBox="0 0 100 100"
                                 preserveAspectRatio="none"
                             >
-                                <g transform="matrix(1 0 0 -1 0 100)">
+                                // vertical bars are drawn in a coordinate system with y pointing
+                                // upwards, horizontal bars simply swap x and y
+                                <g transform=if horizontal {
+                                    "matrix(1 0 0 1 0 0)"
+                                } else {
+                                    "matrix(1 0 0 -1 0 100)"
+                                }>
                                     <rect
                                         node_ref=el
-                                        x=move || (5.0 + 95.0 / num_bars.get() as f64 * i as f64)
+                                        x=move || {
+                                            if horizontal {
+                                                value_pos(bottom, bar.secondary)
+                                            } else {
+                                                bar.band_start
+                                            }
+                                        }
+
                                         y=move || {
-                                            if v > 0.0 {
-                                                100.0 * -tick_config.get().min_point
-                                                    / (tick_config.get().max_point
-                                                        - tick_config.get().min_point)
+                                            if horizontal {
+                                                bar.band_start
                                             } else {
-                                                100.0 * (v - tick_config.get().min_point)
-                                                    / (tick_config.get().max_point
-                                                        - tick_config.get().min_point)
+                                                value_pos(bottom, bar.secondary)
                                             }
                                         }
 
-                                        width=move || (80.0 / num_bars.get() as f64)
+                                        width=move || {
+                                            if horizontal { length() } else { bar.band_width }
+                                        }
+
                                         height=move || {
-                                            100.0 * v.abs()
-                                                / (tick_config.get().max_point
-                                                    - tick_config.get().min_point)
+                                            if horizontal { bar.band_width } else { length() }
                                         }
 
                                         fill=color.clone()
                                         fill-opacity=move || {
-                                            if is_hovered.get() { "0.8" } else { "0.6" }
+                                            if is_selected() {
+                                                "1"
+                                            } else if is_active.get() {
+                                                "0.8"
+                                            } else {
+                                                "0.6"
+                                            }
+                                        }
+
+                                        stroke=move || {
+                                            if is_selected() { "#000".to_string() } else { color.clone() }
                                         }
 
-                                        stroke=color
                                         stroke-width=move || {
-                                            if is_hovered.get() { "3px" } else { "1px" }
+                                            if is_active.get() || is_selected() { "3px" } else { "1px" }
                                         }
 
                                         vector-effect="non-scaling-stroke"
+                                        cursor=events.is_active().then_some("pointer")
+                                        role=if events.is_active() { "button" } else { "img" }
+                                        aria-label=aria_label
+                                        tabindex=move || focus.tab_index(k)
+                                        on:click={
+                                            let item = item.clone();
+                                            move |_| events.activate(item.clone())
+                                        }
+
+                                        on:keydown=move |ev| {
+                                            if focus.on_key(&ev, k) {
+                                                events.activate(item.clone());
+                                            }
+                                        }
+
+                                        on:focus=move |_| {
+                                            let keyboard = is_focus_visible(el);
+                                            focus.on_focus(k, keyboard);
+                                            if keyboard {
+                                                focus_anchor.set(center_in(el, svg_ref));
+                                            }
+                                        }
+
+                                        on:blur=move |_| {
+                                            focus.on_blur(k);
+                                            focus_anchor.set(None);
+                                        }
                                     ></rect>
                                 </g>
                             </svg>
-                            <Show when=move || is_hovered.get() fallback=|| ()>
-                                <text
-                                    font-size="15px"
-                                    vector-effect="non-scaling-stroke"
-                                    x=move || {
-                                        format!(
-                                            "{}%",
-                                            (15.0 + 85.0 / num_bars.get() as f64 * (i as f64 + 0.5)),
-                                        )
-                                    }
-
-                                    y=move || {
-                                        format!(
-                                            "{}%",
-                                            (100.0
-                                                - 100.0 * (v - tick_config.get().min_point)
-                                                    / (tick_config.get().max_point
-                                                        - tick_config.get().min_point)),
-                                        )
-                                    }
-
-                                    dy=move || { if v > 0.0 { "-5" } else { "15" } }
-                                    dx="-9"
-                                >
-                                    {v}
-                                </text>
-                            </Show>
                         }
                     })
                     .collect_view()
             }}
+            {brush
+                .map(|brush| {
+                    view! { <Brush selected=selected_area area=plot_area color=brush.color/> }
+                })}
+            {tooltip
+                .map(|options| {
+                    view! { <Tooltip data=hovered pointer=tooltip_position size=size options=options/> }
+                })}
+            {accessibility
+                .data_table
+                .then(|| {
+                    view! {
+                        <DataTable
+                            caption=label
+                            headers=Signal::derive(move || {
+                                if single_series.get() {
+                                    ["Category", "", "Value"]
+                                } else {
+                                    ["Series", "Category", "Value"]
+                                }
+                            })
+
+                            rows=bar_data
+                        />
+                    }
+                })}
 
         </svg>
     }
@@ -0,0 +1,197 @@
+use leptos::{ev, svg::Svg, *};
+use leptos_use::use_event_listener;
+
+use crate::{layout::PlotArea, Color};
+
+/// Dragged distance in px below which a drag counts as a click, which clears the selection
+pub(crate) const CLICK_DISTANCE: f64 = 3.0;
+
+/// Shape of the area that is selected by dragging
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BrushMode {
+    /// a range of the x axis, across the whole height of the plot
+    #[default]
+    X,
+    /// a rectangle, a range of both axes
+    XY,
+}
+
+#[derive(Clone, Debug)]
+pub struct BrushOptions {
+    pub mode: BrushMode,
+    pub color: Color<'static>,
+}
+
+impl Default for BrushOptions {
+    fn default() -> Self {
+        Self {
+            mode: BrushMode::default(),
+            color: Color::Hex("#1e66f5"),
+        }
+    }
+}
+
+/// Data selected with a brush
+#[derive(Clone, Debug, PartialEq)]
+pub struct Selection {
+    /// selected range of the x axis. Bar charts count in categories, with the center of the
+    /// first category at 0, the second at 1 and so on
+    pub x: (f64, f64),
+    /// selected range of the (primary) y axis, if selecting rectangles
+    pub y: Option<(f64, f64)>,
+    /// series and index of the selected points, or series and category of the selected bars
+    pub indices: Vec<(usize, usize)>,
+}
+
+/// Area selected by dragging, in percent of the plot from its top left corner
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct BrushArea {
+    pub x: (f64, f64),
+    pub y: (f64, f64),
+}
+
+impl BrushArea {
+    /// Whether a position in percent of the plot, with y from the top, is inside the area
+    pub fn contains(&self, (x, y): (f64, f64)) -> bool {
+        (self.x.0..=self.x.1).contains(&x) && (self.y.0..=self.y.1).contains(&y)
+    }
+}
+
+/// Area between two positions in percent of the plot, limited to the plot
+fn brush_area(mode: BrushMode, from: (f64, f64), to: (f64, f64)) -> BrushArea {
+    let range = |a: f64, b: f64| (a.min(b).clamp(0.0, 100.0), a.max(b).clamp(0.0, 100.0));
+    BrushArea {
+        x: range(from.0, to.0),
+        y: match mode {
+            BrushMode::X => (0.0, 100.0),
+            BrushMode::XY => range(from.1, to.1),
+        },
+    }
+}
+
+/// Lets the user select an area of the plot by dragging on `target`, the root element of
+/// a chart with its plot at `area`.
+///
+/// Returns the area while it's being dragged, `on_end` is called with the final area when
+/// the pointer is released, or with `None` for clicks.
+pub(crate) fn use_brush(
+    target: NodeRef<Svg>,
+    area: Signal<PlotArea>,
+    mode: BrushMode,
+    on_end: impl Fn(Option<BrushArea>) + 'static,
+) -> Signal<Option<BrushArea>> {
+    // pointer that started the drag, with its client and plot position
+    let start = store_value(None::<(i32, (f64, f64), (f64, f64))>);
+    let (dragged, set_dragged) = create_signal(None::<BrushArea>);
+    // position in percent of the plot
+    let to_plot = move |(x, y): (f64, f64)| {
+        target.get_untracked().map(|el| {
+            let rect = el.get_bounding_client_rect();
+            let area = area.get_untracked();
+            (
+                (100.0 * (x - rect.left()) / rect.width() - area.x) * 100.0 / area.width,
+                (100.0 * (y - rect.top()) / rect.height() - area.y) * 100.0 / area.height,
+            )
+        })
+    };
+
+    let _ = use_event_listener(target, ev::pointerdown, move |e| {
+        let client = (e.client_x() as f64, e.client_y() as f64);
+        let Some(position) = to_plot(client) else {
+            return;
+        };
+        let inside = (0.0..=100.0).contains(&position.0) && (0.0..=100.0).contains(&position.1);
+        if e.button() != 0 || !inside {
+            return;
+        }
+        if let Some(el) = target.get_untracked() {
+            let _ = el.set_pointer_capture(e.pointer_id());
+        }
+        start.set_value(Some((e.pointer_id(), client, position)));
+    });
+    let _ = use_event_listener(target, ev::pointermove, move |e| {
+        let Some((id, _, from)) = start.get_value() else {
+            return;
+        };
+        if id != e.pointer_id() {
+            return;
+        }
+        if let Some(to) = to_plot((e.client_x() as f64, e.client_y() as f64)) {
+            set_dragged.set(Some(brush_area(mode, from, to)));
+        }
+    });
+    let _ = use_event_listener(target, ev::pointerup, move |e| {
+        let Some((id, client, from)) = start.get_value() else {
+            return;
+        };
+        if id != e.pointer_id() {
+            return;
+        }
+        start.set_value(None);
+        set_dragged.set(None);
+        let distance = (e.client_x() as f64 - client.0).hypot(e.client_y() as f64 - client.1);
+        let to = to_plot((e.client_x() as f64, e.client_y() as f64));
+        on_end(
+            to.filter(|_| distance >= CLICK_DISTANCE)
+                .map(|to| brush_area(mode, from, to)),
+        );
+    });
+    let _ = use_event_listener(target, ev::pointercancel, move |_| {
+        start.set_value(None);
+        set_dragged.set(None);
+    });
+    dragged.into()
+}
+
+/// Highlighted selection of a brush, `selected` is in percent of the plot `area`
+#[component]
+pub(crate) fn Brush(
+    #[prop(into)] selected: Signal<Option<BrushArea>>,
+    #[prop(into)] area: Signal<PlotArea>,
+    color: Color<'static>,
+) -> impl IntoView {
+    let color = String::from(color);
+    view! {
+        {move || {
+            selected
+                .get()
+                .map(|selected| {
+                    let plot = area.get();
+                    view! {
+                        <rect
+                            x=format!("{}%", plot.x_at(selected.x.0))
+                            y=format!("{}%", plot.y_at(selected.y.0))
+                            width=format!("{}%", plot.width * (selected.x.1 - selected.x.0) / 100.0)
+                            height=format!(
+                                "{}%",
+                                plot.height * (selected.y.1 - selected.y.0) / 100.0,
+                            )
+
+                            fill=color.clone()
+                            fill-opacity="0.15"
+                            stroke=color.clone()
+                            stroke-width="1px"
+                            vector-effect="non-scaling-stroke"
+                            pointer-events="none"
+                        ></rect>
+                    }
+                })
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brush_areas() {
+        let area = brush_area(BrushMode::X, (60.0, 20.0), (-10.0, 30.0));
+        assert_eq!(area.x, (0.0, 60.0));
+        assert_eq!(area.y, (0.0, 100.0));
+        let area = brush_area(BrushMode::XY, (60.0, 20.0), (-10.0, 30.0));
+        assert_eq!(area.y, (20.0, 30.0));
+        assert!(area.contains((10.0, 25.0)));
+        assert!(!area.contains((10.0, 35.0)));
+    }
+}
@@ -66,16 +66,19 @@ where
 }
 
 pub trait ChartColor {
-    fn color_for_index(&self, i: usize, total: usize) -> Color;
+    fn color_for_index(&self, i: usize, total: usize) -> Color<'_>;
 }
 impl ChartColor for Palette<'_> {
-    fn color_for_index(&self, i: usize, _total: usize) -> Color {
+    fn color_for_index(&self, i: usize, _total: usize) -> Color<'_> {
         self.0[i % self.0.len()].clone()
     }
 }
 impl ChartColor for Gradient<'_> {
     /// Implements linear interpolation with gamma correction
-    fn color_for_index(&self, i: usize, total: usize) -> Color {
+    fn color_for_index(&self, i: usize, total: usize) -> Color<'_> {
+        if total <= 1 {
+            return self.from.clone();
+        }
         let total = total - 1;
         let from_color: (u8, u8, u8) = self.from.clone().into();
         let to_color: (u8, u8, u8) = self.to.clone().into();
@@ -181,5 +184,9 @@ mod tests {
             <(u8, u8, u8)>::from(gradient.color_for_index(2, 4)),
             (2, 97, 202)
         );
+        assert_eq!(
+            <(u8, u8, u8)>::from(gradient.color_for_index(0, 1)),
+            (0, 100, 200)
+        );
     }
 }
@@ -0,0 +1,171 @@
+use leptos::{ev::KeyboardEvent, html::ElementDescriptor, svg::Svg, *};
+
+/// Index of the item that a key press on the item at `index` moves to, out of `count` items
+fn next_index(key: &str, index: usize, count: usize) -> Option<usize> {
+    match key {
+        "ArrowRight" | "ArrowDown" => Some((index + 1).min(count.saturating_sub(1))),
+        "ArrowLeft" | "ArrowUp" => Some(index.saturating_sub(1)),
+        "Home" => Some(0),
+        "End" => Some(count.saturating_sub(1)),
+        _ => None,
+    }
+}
+
+/// Point a key press moves to from point `index` of series `series`, where `lengths` are the
+/// numbers of points of all series. Left and right move along the series, up and down switch
+/// between series. Returns `None` for other keys and series that don't exist (anymore).
+pub(crate) fn next_point(
+    key: &str,
+    (series, index): (usize, usize),
+    lengths: &[usize],
+) -> Option<(usize, usize)> {
+    let length = *lengths.get(series)?;
+    let index = index.min(length.saturating_sub(1));
+    let clamped = |series: usize| (series, index.min(lengths[series].saturating_sub(1)));
+    match key {
+        "ArrowUp" => Some(clamped(series.saturating_sub(1))),
+        "ArrowDown" => Some(clamped((series + 1).min(lengths.len() - 1))),
+        "ArrowLeft" | "ArrowRight" | "Home" | "End" => {
+            next_index(key, index, length).map(|index| (series, index))
+        }
+        _ => None,
+    }
+}
+
+/// First point of the first series with points, where keyboard navigation starts
+pub(crate) fn first_point(lengths: &[usize]) -> Option<(usize, usize)> {
+    lengths
+        .iter()
+        .position(|l| *l > 0)
+        .map(|series| (series, 0))
+}
+
+/// Whether `key` activates the focused item, like a click
+pub(crate) fn is_activation(key: &str) -> bool {
+    matches!(key, "Enter" | " ")
+}
+
+/// Center of `el` in px from the top left corner of `root`, where the tooltip of a focused
+/// item is shown
+pub(crate) fn center_in<El: ElementDescriptor + Clone + 'static>(
+    el: NodeRef<El>,
+    root: NodeRef<Svg>,
+) -> Option<(f64, f64)> {
+    let (el, root) = (el.get_untracked()?, root.get_untracked()?);
+    let (rect, root) = (
+        el.into_any().get_bounding_client_rect(),
+        root.get_bounding_client_rect(),
+    );
+    Some((
+        rect.left() + rect.width() / 2.0 - root.left(),
+        rect.top() + rect.height() / 2.0 - root.top(),
+    ))
+}
+
+/// Whether `el` is focused from the keyboard rather than by a click, in browsers that
+/// support `:focus-visible`
+pub(crate) fn is_focus_visible<El: ElementDescriptor + Clone + 'static>(el: NodeRef<El>) -> bool {
+    el.get_untracked()
+        .and_then(|el| el.into_any().matches(":focus-visible").ok())
+        .unwrap_or(true)
+}
+
+/// Moves the keyboard focus between the items of a chart, e.g. its bars.
+///
+/// Only one item can be reached with Tab, the one that was focused last. The arrow keys
+/// move to the previous or next item, Home and End to the first or last one.
+#[derive(Clone, Copy)]
+pub(crate) struct FocusNavigation {
+    /// item that is reached with Tab
+    current: RwSignal<usize>,
+    focused: RwSignal<Option<usize>>,
+    count: Signal<usize>,
+}
+
+impl FocusNavigation {
+    pub fn new(count: Signal<usize>) -> Self {
+        Self {
+            current: create_rw_signal(0),
+            focused: create_rw_signal(None),
+            count,
+        }
+    }
+
+    /// `tabindex` of the item at `index`
+    pub fn tab_index(&self, index: usize) -> &'static str {
+        if self.current.get().min(self.count.get().saturating_sub(1)) == index {
+            "0"
+        } else {
+            "-1"
+        }
+    }
+
+    /// Whether the item at `index` is focused from the keyboard
+    pub fn is_focused(&self, index: usize) -> bool {
+        self.focused.get() == Some(index)
+    }
+
+    /// Handles the item at `index` getting focused. Items that are focused by a click become
+    /// the one reached with Tab, but aren't highlighted like keyboard focus
+    pub fn on_focus(&self, index: usize, keyboard: bool) {
+        self.current.set(index);
+        self.focused.set(keyboard.then_some(index));
+    }
+
+    pub fn on_blur(&self, index: usize) {
+        if self.focused.get_untracked() == Some(index) {
+            self.focused.set(None);
+        }
+    }
+
+    /// Calls `focus` to focus the element of the item at `index` when it's navigated to
+    pub fn register(&self, index: usize, focus: impl Fn() + 'static) {
+        let focused = self.focused;
+        create_effect(move |_| {
+            if focused.get() == Some(index) {
+                focus();
+            }
+        });
+    }
+
+    /// Handles a key pressed on the item at `index`, returns whether it activates the item
+    pub fn on_key(&self, ev: &KeyboardEvent, index: usize) -> bool {
+        let key = ev.key();
+        if is_activation(&key) {
+            ev.prevent_default();
+            return true;
+        }
+        if let Some(next) = next_index(&key, index, self.count.get_untracked()) {
+            ev.prevent_default();
+            self.current.set(next);
+            self.focused.set(Some(next));
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrow_keys() {
+        assert_eq!(next_index("ArrowRight", 1, 3), Some(2));
+        assert_eq!(next_index("ArrowDown", 2, 3), Some(2));
+        assert_eq!(next_index("ArrowLeft", 0, 3), Some(0));
+        assert_eq!(next_index("End", 0, 3), Some(2));
+        assert_eq!(next_index("a", 0, 3), None);
+        assert!(is_activation(" "));
+
+        let lengths = [3, 2];
+        assert_eq!(next_point("ArrowRight", (0, 1), &lengths), Some((0, 2)));
+        assert_eq!(next_point("ArrowDown", (0, 2), &lengths), Some((1, 1)));
+        assert_eq!(next_point("ArrowDown", (1, 0), &lengths), Some((1, 0)));
+        assert_eq!(next_point("End", (1, 0), &lengths), Some((1, 1)));
+        assert_eq!(next_point("ArrowUp", (2, 0), &lengths), None);
+        assert_eq!(first_point(&[0, 2]), Some((1, 0)));
+        assert_eq!(first_point(&[0]), None);
+        // points beyond a series that got shorter
+        assert_eq!(next_point("ArrowLeft", (1, 5), &lengths), Some((1, 0)));
+    }
+}
@@ -0,0 +1,359 @@
+/// Turns values into the labels shown on axes and when hovering
+pub trait TickFormat {
+    fn format(&self, value: f64) -> String;
+
+    /// Label of `value` with the separators of `locale`. The built-in formats apply them to
+    /// their numbers, other formats are shown as `format` returns them
+    fn format_localized(&self, value: f64, locale: Locale) -> String {
+        let _ = locale;
+        self.format(value)
+    }
+}
+
+/// Shortest representation of the value, rounded to 12 significant digits so that
+/// floating point noise like `0.30000000000000004` doesn't show up
+pub struct Plain;
+
+/// Fixed number of decimals
+pub struct Decimals(pub usize);
+
+/// Scales values with SI prefixes (1.2k, 3.4M, 5µ), with at most `decimals` decimals
+pub struct SiPrefix {
+    pub decimals: usize,
+}
+
+/// Percentages, values are taken as fractions of 1 if `fraction` is set and as
+/// percentages otherwise
+pub struct Percent {
+    pub decimals: usize,
+    pub fraction: bool,
+}
+
+/// Amounts of money with thousands separators, e.g. `$1,234.50`
+pub struct Currency {
+    pub symbol: String,
+    pub decimals: usize,
+}
+
+/// Amounts of data, in powers of 1024 (KiB, MiB, ...) if `binary` is set and in
+/// powers of 1000 (kB, MB, ...) otherwise
+pub struct Bytes {
+    pub binary: bool,
+    pub decimals: usize,
+}
+
+/// Decimal and thousands separators of a language or region.
+///
+/// The default leaves labels as they are formatted, with `.` as decimal separator and
+/// without thousands separators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Locale {
+    pub decimal: char,
+    pub thousands: Option<char>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self {
+            decimal: '.',
+            thousands: None,
+        }
+    }
+}
+
+impl Locale {
+    /// 1,234.5
+    pub const EN: Locale = Locale {
+        decimal: '.',
+        thousands: Some(','),
+    };
+    /// 1.234,5
+    pub const DE: Locale = Locale {
+        decimal: ',',
+        thousands: Some('.'),
+    };
+    /// 1'234.5
+    pub const CH: Locale = Locale {
+        decimal: '.',
+        thousands: Some('\''),
+    };
+    /// 1 234,5 (with a narrow no-break space)
+    pub const FR: Locale = Locale {
+        decimal: ',',
+        thousands: Some('\u{202f}'),
+    };
+
+    /// Applies the separators to `number`, which is formatted with `.` as decimal separator
+    /// and without thousands separators like `format!("{:.2}", value)` does
+    pub fn localize_number(&self, number: &str) -> String {
+        if *self == Locale::default() {
+            return number.to_string();
+        }
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number),
+        };
+        let (integer, fraction) = match number.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number, None),
+        };
+        let mut localized = sign.to_string();
+        for (i, digit) in integer.chars().enumerate() {
+            if let Some(thousands) = self.thousands {
+                if i > 0 && (integer.len() - i) % 3 == 0 {
+                    localized.push(thousands);
+                }
+            }
+            localized.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            localized.push(self.decimal);
+            localized.push_str(fraction);
+        }
+        localized
+    }
+}
+
+/// takes a lambda that takes a value and outputs its label
+pub struct CalculatedFormat<F>
+where
+    F: Fn(f64) -> String,
+{
+    pub func: F,
+}
+
+/// Removes trailing zeros after the decimal point, and the point if nothing remains
+fn trim_zeros(s: String) -> String {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        s
+    }
+}
+
+/// Value divided by the largest power of `base` that keeps it >= 1, and the exponent
+/// of that power, with the exponent limited to `min_exponent..=max_exponent`
+fn scaled(value: f64, base: f64, min_exponent: i32, max_exponent: i32) -> (f64, i32) {
+    if value == 0.0 || !value.is_finite() {
+        return (value, 0);
+    }
+    let exponent = (value.abs().log(base).floor() as i32).clamp(min_exponent, max_exponent);
+    (value / base.powi(exponent), exponent)
+}
+
+impl TickFormat for Plain {
+    fn format(&self, value: f64) -> String {
+        let rounded = format!("{:.11e}", value).parse::<f64>().unwrap_or(value);
+        format!("{}", rounded)
+    }
+
+    fn format_localized(&self, value: f64, locale: Locale) -> String {
+        locale.localize_number(&self.format(value))
+    }
+}
+
+impl TickFormat for Decimals {
+    fn format(&self, value: f64) -> String {
+        format!("{:.*}", self.0, value)
+    }
+
+    fn format_localized(&self, value: f64, locale: Locale) -> String {
+        locale.localize_number(&self.format(value))
+    }
+}
+
+impl TickFormat for SiPrefix {
+    fn format(&self, value: f64) -> String {
+        self.format_localized(value, Locale::default())
+    }
+
+    fn format_localized(&self, value: f64, locale: Locale) -> String {
+        const PREFIXES: [&str; 9] = ["p", "n", "µ", "m", "", "k", "M", "G", "T"];
+        let (value, exponent) = scaled(value, 1000.0, -4, 4);
+        let number = trim_zeros(format!("{:.*}", self.decimals, value));
+        // rounding can carry over into the next prefix, e.g. 999.96 to 1000.0
+        if number.trim_start_matches('-') == "1000" && exponent < 4 {
+            let sign = if value < 0.0 { "-" } else { "" };
+            return format!("{}1{}", sign, PREFIXES[(exponent + 5) as usize]);
+        }
+        format!(
+            "{}{}",
+            locale.localize_number(&number),
+            PREFIXES[(exponent + 4) as usize]
+        )
+    }
+}
+
+impl TickFormat for Percent {
+    fn format(&self, value: f64) -> String {
+        self.format_localized(value, Locale::default())
+    }
+
+    fn format_localized(&self, value: f64, locale: Locale) -> String {
+        let value = if self.fraction { value * 100.0 } else { value };
+        format!(
+            "{}%",
+            locale.localize_number(&format!("{:.*}", self.decimals, value))
+        )
+    }
+}
+
+impl TickFormat for Currency {
+    fn format(&self, value: f64) -> String {
+        self.format_localized(value, Locale::EN)
+    }
+
+    /// Amounts always have thousands separators, the default locale uses the ones of
+    /// [`Locale::EN`]
+    fn format_localized(&self, value: f64, locale: Locale) -> String {
+        let locale = if locale == Locale::default() {
+            Locale::EN
+        } else {
+            locale
+        };
+        let number = format!("{:.*}", self.decimals, value.abs());
+        let sign = if value < 0.0 && number.chars().any(|c| c != '0' && c != '.') {
+            "-"
+        } else {
+            ""
+        };
+        format!("{}{}{}", sign, self.symbol, locale.localize_number(&number))
+    }
+}
+
+impl TickFormat for Bytes {
+    fn format(&self, value: f64) -> String {
+        self.format_localized(value, Locale::default())
+    }
+
+    fn format_localized(&self, value: f64, locale: Locale) -> String {
+        let (base, units) = if self.binary {
+            (1024.0, ["B", "KiB", "MiB", "GiB", "TiB", "PiB"])
+        } else {
+            (1000.0, ["B", "kB", "MB", "GB", "TB", "PB"])
+        };
+        let (value, exponent) = scaled(value, base, 0, 5);
+        if exponent == 0 {
+            return format!("{} {}", Plain.format_localized(value, locale), units[0]);
+        }
+        format!(
+            "{} {}",
+            locale.localize_number(&trim_zeros(format!("{:.*}", self.decimals, value))),
+            units[exponent as usize]
+        )
+    }
+}
+
+impl<F> TickFormat for CalculatedFormat<F>
+where
+    F: Fn(f64) -> String,
+{
+    fn format(&self, value: f64) -> String {
+        (self.func)(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats() {
+        assert_eq!(Plain.format(0.1 + 0.2), "0.3");
+        assert_eq!(Plain.format(-1500.0), "-1500");
+        assert_eq!(Decimals(2).format(1.0 / 3.0), "0.33");
+        assert_eq!(SiPrefix { decimals: 1 }.format(1234.0), "1.2k");
+        assert_eq!(SiPrefix { decimals: 1 }.format(3_400_000.0), "3.4M");
+        assert_eq!(SiPrefix { decimals: 1 }.format(0.005), "5m");
+        assert_eq!(SiPrefix { decimals: 1 }.format(999_960.0), "1M");
+        assert_eq!(SiPrefix { decimals: 1 }.format(0.0), "0");
+        assert_eq!(
+            Percent {
+                decimals: 0,
+                fraction: true
+            }
+            .format(0.25),
+            "25%"
+        );
+        let dollars = Currency {
+            symbol: "$".to_string(),
+            decimals: 2,
+        };
+        assert_eq!(dollars.format(1234.5), "$1,234.50");
+        assert_eq!(dollars.format(-999.0), "-$999.00");
+        assert_eq!(dollars.format(1_000_000.0), "$1,000,000.00");
+        assert_eq!(
+            Bytes {
+                binary: true,
+                decimals: 1
+            }
+            .format(1536.0),
+            "1.5 KiB"
+        );
+        assert_eq!(
+            Bytes {
+                binary: false,
+                decimals: 1
+            }
+            .format(512.0),
+            "512 B"
+        );
+        assert_eq!(
+            CalculatedFormat {
+                func: |v| format!("{} ms", v)
+            }
+            .format(5.0),
+            "5 ms"
+        );
+    }
+
+    #[test]
+    fn locales() {
+        assert_eq!(Plain.format_localized(-1234.5, Locale::EN), "-1,234.5");
+        assert_eq!(Plain.format_localized(1234.5, Locale::DE), "1.234,5");
+        assert_eq!(Plain.format_localized(123.0, Locale::DE), "123");
+        assert_eq!(Decimals(1).format_localized(-123.0, Locale::EN), "-123.0");
+        assert_eq!(Plain.format_localized(1234.5, Locale::CH), "1'234.5");
+        assert_eq!(
+            SiPrefix { decimals: 1 }.format_localized(1234.0, Locale::CH),
+            "1.2k"
+        );
+        assert_eq!(
+            SiPrefix { decimals: 1 }.format_localized(1234.0, Locale::DE),
+            "1,2k"
+        );
+        let dollars = Currency {
+            symbol: "$".to_string(),
+            decimals: 2,
+        };
+        assert_eq!(
+            dollars.format_localized(1234.5, Locale::default()),
+            "$1,234.50"
+        );
+        assert_eq!(dollars.format_localized(-1234.5, Locale::DE), "-$1.234,50");
+        assert_eq!(
+            Percent {
+                decimals: 1,
+                fraction: true
+            }
+            .format_localized(0.333, Locale::DE),
+            "33,3%"
+        );
+        assert_eq!(
+            Bytes {
+                binary: true,
+                decimals: 1
+            }
+            .format_localized(1536.0, Locale::DE),
+            "1,5 KiB"
+        );
+        // labels of other formats are left as they are
+        let calculated = CalculatedFormat {
+            func: |v: f64| format!("{}.01.05", v),
+        };
+        assert_eq!(
+            calculated.format_localized(2024.0, Locale::DE),
+            "2024.01.05"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{layout::PlotArea, Color};
+use leptos::*;
+
+#[derive(Clone, Debug)]
+pub struct GridOptions {
+    /// lines across the plot at the ticks of the vertical axis
+    pub horizontal: bool,
+    /// lines across the plot at the ticks of the horizontal axis
+    pub vertical: bool,
+    /// also draws lines at the minor ticks, at half the opacity
+    pub minor: bool,
+    pub color: Color<'static>,
+    /// `stroke-dasharray` of the lines, e.g. `"4 2"`, solid lines if not set
+    pub dash: Option<String>,
+    pub opacity: f64,
+}
+
+impl Default for GridOptions {
+    fn default() -> Self {
+        Self {
+            horizontal: true,
+            vertical: true,
+            minor: false,
+            color: Color::Hex("#cccccc"),
+            dash: None,
+            opacity: 1.0,
+        }
+    }
+}
+
+/// Lines across the plot area, drawn behind the data.
+///
+/// Positions are in percent of the plot area, like the ticks of [`crate::YAxis`] (from the
+/// top) and [`crate::XAxis`] (from the left).
+#[component]
+pub fn Grid(
+    /// positions of the horizontal lines, from the top
+    #[prop(into)]
+    horizontal: MaybeSignal<Vec<f64>>,
+    /// positions of the vertical lines, from the left
+    #[prop(into)]
+    vertical: MaybeSignal<Vec<f64>>,
+    #[prop(optional, into)] horizontal_minor: MaybeSignal<Vec<f64>>,
+    #[prop(optional, into)] vertical_minor: MaybeSignal<Vec<f64>>,
+    options: GridOptions,
+    #[prop(into)] area: Signal<PlotArea>,
+) -> impl IntoView {
+    let GridOptions {
+        horizontal: show_horizontal,
+        vertical: show_vertical,
+        minor,
+        color,
+        dash,
+        opacity,
+    } = options;
+    let color = String::from(color);
+    // every line as (x1, y1, x2, y2, opacity)
+    let lines = move || {
+        let mut lines = Vec::new();
+        let mut add = |positions: Vec<f64>, horizontal: bool, opacity: f64| {
+            lines.extend(positions.into_iter().map(|p| {
+                if horizontal {
+                    (0.0, p, 100.0, p, opacity)
+                } else {
+                    (p, 0.0, p, 100.0, opacity)
+                }
+            }))
+        };
+        if show_horizontal {
+            add(horizontal.get(), true, opacity);
+            if minor {
+                add(horizontal_minor.get(), true, opacity / 2.0);
+            }
+        }
+        if show_vertical {
+            add(vertical.get(), false, opacity);
+            if minor {
+                add(vertical_minor.get(), false, opacity / 2.0);
+            }
+        }
+        lines
+    };
+
+    view! {
+        <svg
+            x=move || format!("{}%", area.get().x)
+            y=move || format!("{}%", area.get().y)
+            width=move || format!("{}%", area.get().width)
+            height=move || format!("{}%", area.get().height)
+            viewBox="0 0 100 100"
+            preserveAspectRatio="none"
+        >
+            {move || {
+                lines()
+                    .into_iter()
+                    .map(|(x1, y1, x2, y2, opacity)| {
+                        view! {
+                            <line
+                                x1=x1
+                                y1=y1
+                                x2=x2
+                                y2=y2
+                                stroke=color.clone()
+                                stroke-opacity=opacity
+                                stroke-dasharray=dash.clone()
+                                stroke-width="1px"
+                                vector-effect="non-scaling-stroke"
+                            ></line>
+                        }
+                    })
+                    .collect_view()
+            }}
+
+        </svg>
+    }
+}
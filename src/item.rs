@@ -0,0 +1,100 @@
+use leptos::*;
+
+/// Bar, point or pie segment of a chart, as reported when it's clicked or selected
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartItem {
+    /// index of the series, 0 for charts with a single series
+    pub series: usize,
+    /// index of the value within its series, or of the category for bars
+    pub index: usize,
+    pub value: f64,
+    /// label of the bar or segment, or name of the series of a point
+    pub label: String,
+}
+
+/// Reports activated (e.g. clicked) items of a chart and keeps track of the selected one
+#[derive(Clone, Copy)]
+pub(crate) struct ItemEvents {
+    on_click: Option<Callback<ChartItem>>,
+    /// set if items can be selected
+    selected: Option<RwSignal<Option<ChartItem>>>,
+}
+
+impl ItemEvents {
+    /// Selection is kept in `selected` if `selectable` is set, or in a new signal if that
+    /// isn't passed
+    pub fn new(
+        on_click: Option<Callback<ChartItem>>,
+        selected: Option<RwSignal<Option<ChartItem>>>,
+        selectable: bool,
+    ) -> Self {
+        Self {
+            on_click,
+            selected: selectable.then(|| selected.unwrap_or_else(|| create_rw_signal(None))),
+        }
+    }
+
+    /// Whether activating items does anything
+    pub fn is_active(&self) -> bool {
+        self.on_click.is_some() || self.selected.is_some()
+    }
+
+    /// Reports `item` and selects it, or deselects it if it was selected already
+    pub fn activate(&self, item: ChartItem) {
+        if let Some(selected) = self.selected {
+            selected.update(|selected| {
+                *selected = match selected {
+                    Some(s) if s.series == item.series && s.index == item.index => None,
+                    _ => Some(item.clone()),
+                }
+            });
+        }
+        if let Some(on_click) = self.on_click {
+            on_click.call(item);
+        }
+    }
+
+    /// Selected item, if any
+    pub fn selected(&self) -> Option<ChartItem> {
+        self.selected.and_then(|selected| selected.get())
+    }
+
+    /// Whether the item at `index` of `series` is selected
+    pub fn is_selected(&self, series: usize, index: usize) -> bool {
+        self.selected.is_some_and(|selected| {
+            selected.with(|s| {
+                s.as_ref()
+                    .is_some_and(|s| s.series == series && s.index == index)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggles_selection() {
+        let runtime = create_runtime();
+        let clicked = create_rw_signal(0);
+        let events = ItemEvents::new(
+            Some(Callback::new(move |_| clicked.update(|c| *c += 1))),
+            None,
+            true,
+        );
+        let item = ChartItem {
+            series: 1,
+            index: 2,
+            value: 3.0,
+            label: "c".to_string(),
+        };
+        events.activate(item.clone());
+        assert!(events.is_selected(1, 2));
+        assert!(!events.is_selected(0, 2));
+        events.activate(item);
+        assert!(!events.is_selected(1, 2));
+        assert_eq!(clicked.get_untracked(), 2);
+        runtime.dispose();
+    }
+}
@@ -0,0 +1,249 @@
+use leptos::{html::ElementDescriptor, *};
+use leptos_use::use_resize_observer;
+
+/// Size assumed for a chart before it has been measured, e.g. during SSR
+pub const FALLBACK_SIZE: (f64, f64) = (300.0, 200.0);
+
+/// Rectangular region of a chart, in percent of the chart's width and height
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlotArea {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl PlotArea {
+    /// The whole chart
+    pub const FULL: PlotArea = PlotArea {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// Shrinks the area by the given margins, in percent of the chart
+    pub fn inset(&self, top: f64, right: f64, bottom: f64, left: f64) -> Self {
+        Self {
+            x: self.x + left,
+            y: self.y + top,
+            width: (self.width - left - right).max(0.0),
+            height: (self.height - top - bottom).max(0.0),
+        }
+    }
+
+    /// Maps a horizontal position in percent of this area to percent of the chart
+    pub fn x_at(&self, percent: f64) -> f64 {
+        self.x + self.width * percent / 100.0
+    }
+
+    /// Maps a vertical position in percent of this area to percent of the chart
+    pub fn y_at(&self, percent: f64) -> f64 {
+        self.y + self.height * percent / 100.0
+    }
+}
+
+/// Space in px around the plot, in addition to the space taken by the axes and their labels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Margins {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+impl Margins {
+    /// The same margin on all sides
+    pub fn uniform(margin: f64) -> Self {
+        Self {
+            top: margin,
+            right: margin,
+            bottom: margin,
+            left: margin,
+        }
+    }
+}
+
+impl Default for Margins {
+    fn default() -> Self {
+        Self::uniform(8.0)
+    }
+}
+
+/// Sizes of the plot's surroundings, from which charts compute their plot area
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayoutOptions {
+    pub margins: Margins,
+    /// font size of the tick labels in px
+    pub font_size: f64,
+    /// length of the tick marks in px, minor ticks are half as long
+    pub tick_length: f64,
+    /// share of the chart's width in percent that labels of the vertical axis can take up,
+    /// longer labels are shrunk
+    pub max_label_width: f64,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        Self {
+            margins: Margins::default(),
+            font_size: 12.0,
+            tick_length: 6.0,
+            max_label_width: 30.0,
+        }
+    }
+}
+
+impl LayoutOptions {
+    /// Distance in px between an axis and its tick labels
+    pub fn label_offset(&self) -> f64 {
+        self.tick_length + self.font_size * 0.25
+    }
+
+    /// Estimated width of a tick label in px.
+    ///
+    /// Like the legend, this assumes an average glyph width of 0.6em so that the layout
+    /// also works without a DOM.
+    pub fn label_width(&self, label: &str) -> f64 {
+        self.font_size * 0.6 * label.chars().count() as f64
+    }
+
+    /// Largest width in px of labels of the vertical axis
+    pub fn max_label_width(&self, size: (f64, f64)) -> f64 {
+        size.0 * self.max_label_width / 100.0
+    }
+
+    /// Width in px that is reserved for the labels of a vertical axis with `ticks`, the
+    /// estimated width of the longest label up to [`LayoutOptions::max_label_width`].
+    /// Rendered labels that turn out wider are shrunk to fit.
+    pub fn labels_width(&self, ticks: &[(f64, String)], size: (f64, f64)) -> f64 {
+        ticks
+            .iter()
+            .map(|(_, label)| self.label_width(label))
+            .fold(0.0, f64::max)
+            .min(self.max_label_width(size))
+    }
+
+    /// Plot area within `region` that leaves room for the margins and the ticks of the axes.
+    ///
+    /// `y_ticks` are positions in percent from the top and `x_ticks` in percent from the left,
+    /// with their labels, as passed to [`crate::YAxis`] and [`crate::XAxis`]. Labels at the
+    /// ends of an axis stick out by half their size, which is added to the margins where
+    /// needed. Ticks of a [`crate::SecondaryAxis`] go into `secondary_ticks`, which is empty
+    /// for charts without one.
+    pub fn plot_area(
+        &self,
+        region: PlotArea,
+        size: (f64, f64),
+        y_ticks: &[(f64, String)],
+        x_ticks: &[(f64, String)],
+        secondary_ticks: &[(f64, String)],
+    ) -> PlotArea {
+        let (width, height) = (
+            region.width * size.0 / 100.0,
+            region.height * size.1 / 100.0,
+        );
+        let top = y_ticks
+            .iter()
+            .chain(secondary_ticks)
+            .map(|(pos, _)| self.font_size / 2.0 - pos * height / 100.0)
+            .fold(0.0, f64::max);
+        let right = x_ticks
+            .iter()
+            .map(|(pos, label)| self.label_width(label) / 2.0 - (100.0 - pos) * width / 100.0)
+            .fold(0.0, f64::max);
+        let right = if secondary_ticks.is_empty() {
+            right
+        } else {
+            right.max(self.label_offset() + self.labels_width(secondary_ticks, size))
+        };
+        let top = self.margins.top + top;
+        let right = self.margins.right + right;
+        let bottom = self.margins.bottom + self.label_offset() + self.font_size;
+        let left = self.margins.left + self.label_offset() + self.labels_width(y_ticks, size);
+        region.inset(
+            100.0 * top / size.1,
+            100.0 * right / size.0,
+            100.0 * bottom / size.1,
+            100.0 * left / size.0,
+        )
+    }
+}
+
+/// Tracks the rendered size of a chart's root element in pixels.
+///
+/// Falls back to [`FALLBACK_SIZE`] until the element has been measured.
+pub fn use_chart_size<El>(target: NodeRef<El>) -> Signal<(f64, f64)>
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    let (size, set_size) = create_signal(FALLBACK_SIZE);
+    use_resize_observer(target, move |entries, _| {
+        let rect = entries[0].content_rect();
+        if rect.width() > 0.0 && rect.height() > 0.0 {
+            set_size.set((rect.width(), rect.height()));
+        }
+    });
+    size.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticks(ticks: &[(f64, &str)]) -> Vec<(f64, String)> {
+        ticks.iter().map(|(p, l)| (*p, l.to_string())).collect()
+    }
+
+    #[test]
+    fn plot_area() {
+        let layout = LayoutOptions {
+            margins: Margins::uniform(10.0),
+            font_size: 10.0,
+            tick_length: 5.0,
+            max_label_width: 30.0,
+        };
+        // labels are 6px per character and 7.5px away from the axis, the top label sticks
+        // out by 5px and the last x label by 12px
+        let y_ticks = ticks(&[(0.0, "100"), (100.0, "0")]);
+        let x_ticks = ticks(&[(0.0, "0"), (100.0, "1000")]);
+        let area = layout.plot_area(PlotArea::FULL, (200.0, 100.0), &y_ticks, &x_ticks, &[]);
+        assert_eq!(area, PlotArea::new(17.75, 15.0, 71.25, 57.5));
+
+        // the secondary axis needs more space than the last x label
+        let secondary_ticks = ticks(&[(0.0, "50%"), (100.0, "0%")]);
+        let area = layout.plot_area(
+            PlotArea::FULL,
+            (200.0, 100.0),
+            &y_ticks,
+            &x_ticks,
+            &secondary_ticks,
+        );
+        assert_eq!(area.right(), 82.25);
+
+        // long labels are limited to 30% of the width
+        let y_ticks = ticks(&[(50.0, "a very long label indeed")]);
+        let area = layout.plot_area(PlotArea::FULL, (200.0, 100.0), &y_ticks, &[], &[]);
+        assert_eq!(area.x, 38.75);
+        assert_eq!(area.y, 10.0);
+        assert_eq!(layout.labels_width(&y_ticks, (200.0, 100.0)), 60.0);
+        assert_eq!(layout.labels_width(&x_ticks, (200.0, 100.0)), 24.0);
+    }
+}
@@ -1 +1,329 @@
+use std::rc::Rc;
 
+use crate::{layout::PlotArea, ChartColor};
+use leptos::*;
+
+/// Edge of the plot area the legend is attached to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LegendPosition {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+/// Whether the legend is drawn on top of the plot or takes space away from it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LegendPlacement {
+    Inside,
+    Outside,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LegendOptions {
+    pub position: LegendPosition,
+    pub placement: LegendPlacement,
+    /// font size of the labels in px, the swatches and spacing scale with it
+    pub font_size: f64,
+}
+
+impl Default for LegendOptions {
+    fn default() -> Self {
+        Self {
+            position: LegendPosition::Right,
+            placement: LegendPlacement::Outside,
+            font_size: 12.0,
+        }
+    }
+}
+
+/// Positions of the legend entries in px, relative to the top left corner of the legend
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegendLayout {
+    pub entries: Vec<(f64, f64)>,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl LegendOptions {
+    fn is_vertical(&self) -> bool {
+        matches!(self.position, LegendPosition::Left | LegendPosition::Right)
+    }
+
+    fn padding(&self) -> f64 {
+        self.font_size * 0.5
+    }
+
+    fn row_height(&self) -> f64 {
+        self.font_size * 1.5
+    }
+
+    /// Estimated width of an entry (swatch, gap and label)
+    ///
+    /// Text isn't measured so that the layout also works without a DOM, the estimate
+    /// assumes an average glyph width of 0.6em.
+    fn entry_width(&self, label: &str) -> f64 {
+        self.font_size * (1.4 + 0.6 * label.chars().count() as f64)
+    }
+
+    /// Lays out the entries along the legend's edge of the chart.
+    ///
+    /// Horizontal legends (top/bottom) wrap into a new row once `max_extent` px of width
+    /// are used up, vertical legends (left/right) wrap into a new column once `max_extent`
+    /// px of height are used up.
+    pub fn layout(&self, labels: &[String], max_extent: f64) -> LegendLayout {
+        let padding = self.padding();
+        let row_height = self.row_height();
+        let spacing = self.font_size;
+        let max_extent = max_extent - 2.0 * padding;
+        let mut entries = Vec::with_capacity(labels.len());
+        let (mut x, mut y) = (0.0f64, 0.0f64);
+        let (mut width, mut height) = (0.0f64, 0.0f64);
+
+        if self.is_vertical() {
+            let mut column_width = 0.0f64;
+            for label in labels {
+                if y > 0.0 && y + row_height > max_extent {
+                    x += column_width + spacing;
+                    y = 0.0;
+                    column_width = 0.0;
+                }
+                entries.push((x + padding, y + padding));
+                column_width = column_width.max(self.entry_width(label));
+                y += row_height;
+                width = width.max(x + column_width);
+                height = height.max(y);
+            }
+        } else {
+            for label in labels {
+                let entry_width = self.entry_width(label);
+                if x > 0.0 && x + entry_width > max_extent {
+                    x = 0.0;
+                    y += row_height;
+                }
+                entries.push((x + padding, y + padding));
+                x += entry_width;
+                width = width.max(x);
+                x += spacing;
+                height = y + row_height;
+            }
+        }
+
+        LegendLayout {
+            entries,
+            width: width + 2.0 * padding,
+            height: height + 2.0 * padding,
+        }
+    }
+
+    /// Maximum extent in px available to the legend's entries along its edge of `region`
+    pub fn max_extent(&self, region: PlotArea, size: (f64, f64)) -> f64 {
+        if self.is_vertical() {
+            region.height * size.1 / 100.0
+        } else {
+            region.width * size.0 / 100.0
+        }
+    }
+
+    /// Area covered by a legend with the given `layout` when attached to `region`
+    pub fn legend_box(
+        &self,
+        layout: &LegendLayout,
+        region: PlotArea,
+        size: (f64, f64),
+    ) -> PlotArea {
+        let width = (100.0 * layout.width / size.0).min(region.width);
+        let height = (100.0 * layout.height / size.1).min(region.height);
+        let centered_x = region.x + (region.width - width) / 2.0;
+        let centered_y = region.y + (region.height - height) / 2.0;
+        match self.position {
+            LegendPosition::Top => PlotArea::new(centered_x, region.y, width, height),
+            LegendPosition::Bottom => {
+                PlotArea::new(centered_x, region.bottom() - height, width, height)
+            }
+            LegendPosition::Left => PlotArea::new(region.x, centered_y, width, height),
+            LegendPosition::Right => {
+                PlotArea::new(region.right() - width, centered_y, width, height)
+            }
+        }
+    }
+
+    /// Region the legend is attached to, `chart` is the part of the chart shared by the legend
+    /// and the plot (e.g. everything below the title)
+    pub fn region(&self, chart: PlotArea, plot: PlotArea) -> PlotArea {
+        match self.placement {
+            LegendPlacement::Inside => plot,
+            LegendPlacement::Outside => chart,
+        }
+    }
+
+    /// What remains of `region` once a legend for `labels` is placed.
+    ///
+    /// Legends placed inside the plot don't take up any space.
+    pub fn remaining_area(
+        &self,
+        labels: &[String],
+        region: PlotArea,
+        size: (f64, f64),
+    ) -> PlotArea {
+        if self.placement == LegendPlacement::Inside {
+            return region;
+        }
+        let layout = self.layout(labels, self.max_extent(region, size));
+        let legend_box = self.legend_box(&layout, region, size);
+        match self.position {
+            LegendPosition::Top => region.inset(legend_box.height, 0.0, 0.0, 0.0),
+            LegendPosition::Right => region.inset(0.0, legend_box.width, 0.0, 0.0),
+            LegendPosition::Bottom => region.inset(0.0, 0.0, legend_box.height, 0.0),
+            LegendPosition::Left => region.inset(0.0, 0.0, 0.0, legend_box.width),
+        }
+    }
+}
+
+/// Shows which color belongs to which label.
+///
+/// The legend is attached to the `options.position` edge of `region`, which should be the
+/// whole chart for legends placed outside the plot and the plot area for legends placed
+/// inside it. `size` is the size of the chart in px, used to wrap entries that don't fit.
+#[component]
+pub fn Legend(
+    #[prop(into)] labels: MaybeSignal<Vec<String>>,
+    color: Rc<dyn ChartColor>,
+    #[prop(optional)] options: LegendOptions,
+    #[prop(into)] region: Signal<PlotArea>,
+    #[prop(into)] size: Signal<(f64, f64)>,
+) -> impl IntoView {
+    let labels = Signal::derive(move || labels.get());
+    let layout = create_memo(move |_| {
+        labels.with(|l| options.layout(l, options.max_extent(region.get(), size.get())))
+    });
+    let legend_box =
+        create_memo(move |_| layout.with(|l| options.legend_box(l, region.get(), size.get())));
+    let font_size = options.font_size;
+
+    view! {
+        <svg
+            x=move || format!("{}%", legend_box.get().x)
+            y=move || format!("{}%", legend_box.get().y)
+            width=move || format!("{}%", legend_box.get().width)
+            height=move || format!("{}%", legend_box.get().height)
+        >
+            <Show when=move || options.placement == LegendPlacement::Inside fallback=|| ()>
+                <rect
+                    width="100%"
+                    height="100%"
+                    fill="white"
+                    fill-opacity="0.8"
+                    stroke="#ccc"
+                    vector-effect="non-scaling-stroke"
+                ></rect>
+            </Show>
+            {
+                let color = color.clone();
+                move || {
+                    let labels = labels.get();
+                    let num_entries = labels.len();
+                    labels
+                        .into_iter()
+                        .zip(layout.get().entries)
+                        .enumerate()
+                        .map(|(i, (label, (x, y)))| {
+                            let color = String::from(color.color_for_index(i, num_entries));
+                            view! {
+                                <rect
+                                    x=x
+                                    y=y + font_size * 0.25
+                                    width=font_size
+                                    height=font_size
+                                    fill=color.clone()
+                                    fill-opacity="0.6"
+                                    stroke=color
+                                    stroke-width="1px"
+                                    vector-effect="non-scaling-stroke"
+                                ></rect>
+                                <text
+                                    x=x + font_size * 1.4
+                                    y=y + font_size * 0.75
+                                    font-size=format!("{}px", font_size)
+                                    dominant-baseline="central"
+                                >
+                                    {label}
+                                </text>
+                            }
+                        })
+                        .collect_view()
+                }
+            }
+
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn horizontal_wrapping() {
+        // every entry is 10 * (1.4 + 0.6 * 5) = 44px wide, plus 10px spacing
+        let options = LegendOptions {
+            position: LegendPosition::Top,
+            font_size: 10.0,
+            ..Default::default()
+        };
+        let layout = options.layout(&labels(&["aaaaa", "bbbbb", "ccccc"]), 500.0);
+        assert_eq!(layout.entries, vec![(5.0, 5.0), (59.0, 5.0), (113.0, 5.0)]);
+        assert_eq!(layout.width, 162.0);
+        assert_eq!(layout.height, 25.0);
+
+        let layout = options.layout(&labels(&["aaaaa", "bbbbb", "ccccc"]), 120.0);
+        assert_eq!(layout.entries, vec![(5.0, 5.0), (59.0, 5.0), (5.0, 20.0)]);
+        assert_eq!(layout.width, 108.0);
+        assert_eq!(layout.height, 40.0);
+    }
+
+    #[test]
+    fn vertical_wrapping() {
+        let options = LegendOptions {
+            font_size: 10.0,
+            ..Default::default()
+        };
+        let layout = options.layout(&labels(&["a", "b", "c"]), 100.0);
+        assert_eq!(layout.entries, vec![(5.0, 5.0), (5.0, 20.0), (5.0, 35.0)]);
+        assert_eq!(layout.height, 55.0);
+
+        let layout = options.layout(&labels(&["a", "b", "c"]), 50.0);
+        assert_eq!(layout.entries, vec![(5.0, 5.0), (5.0, 20.0), (35.0, 5.0)]);
+        assert_eq!(layout.width, 60.0);
+        assert_eq!(layout.height, 40.0);
+    }
+
+    #[test]
+    fn reserves_space_outside() {
+        let options = LegendOptions {
+            font_size: 10.0,
+            ..Default::default()
+        };
+        // 60px x 40px, see vertical_wrapping
+        let layout = options.layout(&labels(&["a", "b", "c"]), 50.0);
+        let legend_box = options.legend_box(&layout, PlotArea::FULL, (300.0, 200.0));
+        assert_eq!(legend_box, PlotArea::new(80.0, 40.0, 20.0, 20.0));
+        assert_eq!(
+            options.remaining_area(&labels(&["a", "b", "c"]), PlotArea::FULL, (300.0, 50.0)),
+            PlotArea::new(0.0, 0.0, 80.0, 100.0)
+        );
+
+        let options = LegendOptions {
+            placement: LegendPlacement::Inside,
+            ..options
+        };
+        assert_eq!(
+            options.remaining_area(&labels(&["a", "b", "c"]), PlotArea::FULL, (300.0, 50.0)),
+            PlotArea::FULL
+        );
+    }
+}
@@ -1,16 +1,44 @@
 #![feature(iter_map_windows)]
 
+pub mod accessibility;
 pub mod axis;
 pub mod bar;
+pub mod brush;
 pub mod color;
+pub mod focus;
+pub mod format;
+pub mod grid;
+pub mod item;
+pub mod layout;
 pub mod legend;
 pub mod line;
 pub mod pie;
 pub mod point;
+pub mod scatter;
+pub mod time;
+pub mod title;
+pub mod tooltip;
 pub mod utils;
+pub mod zoom;
 
-pub use bar::{BarChart, BarChartOptions};
+pub use accessibility::AccessibilityOptions;
+pub use axis::{AxisSide, SecondaryAxis, XAxis, YAxis};
+pub use bar::{BarChart, BarChartOptions, BarMode, BarOrientation};
+pub use brush::{BrushMode, BrushOptions, Selection};
 pub use color::{CalculatedColor, ChartColor, Color, Gradient, Palette, CATPPUCCIN_COLORS};
-pub use line::{LineChart, LineChartOptions};
+pub use format::{
+    Bytes, CalculatedFormat, Currency, Decimals, Locale, Percent, Plain, SiPrefix, TickFormat,
+};
+pub use grid::{Grid, GridOptions};
+pub use item::ChartItem;
+pub use layout::{LayoutOptions, Margins};
+pub use legend::{Legend, LegendOptions, LegendPlacement, LegendPosition};
+pub use line::{AreaFill, HoverMode, HoveredPoint, LineChart, LineChartOptions};
 pub use pie::{PieChart, PieChartOptions};
-pub use point::{Point, Series};
+pub use point::{NamedSeries, Point, Series};
+pub use scatter::{MarkerShape, ScatterChart, ScatterChartOptions};
+pub use time::{TimeInterval, TimeUnit, Timestamp};
+pub use title::{TitleOptions, Titles};
+pub use tooltip::{Tooltip, TooltipData, TooltipOptions};
+pub use utils::{Domain, Scale};
+pub use zoom::{Viewport, ZoomOptions, ZoomReset};
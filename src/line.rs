@@ -1,110 +1,1039 @@
-use std::cmp;
+use std::{iter, rc::Rc};
 
-use crate::{axis::YAxis, utils, ChartColor, Color, Palette, CATPPUCCIN_COLORS};
+use crate::{
+    accessibility::{count, AccessibilityOptions, DataTable},
+    axis::{AxisSide, SecondaryAxis, XAxis, YAxis},
+    brush::{use_brush, Brush, BrushArea, BrushMode, BrushOptions, Selection, CLICK_DISTANCE},
+    focus::{first_point, is_activation, next_point},
+    format::{Locale, Plain, TickFormat},
+    grid::{Grid, GridOptions},
+    item::{ChartItem, ItemEvents},
+    layout::{use_chart_size, LayoutOptions, PlotArea},
+    legend::{Legend, LegendOptions},
+    point::NamedSeries,
+    title::{TitleOptions, Titles},
+    tooltip::{use_pointer, Tooltip, TooltipData, TooltipOptions},
+    utils::{self, Domain, Scale, TickSpacing},
+    zoom::{use_zoom, Viewport, ZoomOptions, ZoomReset},
+    ChartColor, Color, Palette, CATPPUCCIN_COLORS,
+};
 use itertools::Itertools;
-use leptos::{svg::*, *};
-use leptos_use::*;
+use leptos::{
+    ev::{KeyboardEvent, MouseEvent, PointerEvent},
+    svg::*,
+    *,
+};
+use leptos_use::use_element_hover;
 use num_traits::ToPrimitive;
 
+/// Area below the lines that is filled with a gradient of the line's color
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AreaFill {
+    #[default]
+    None,
+    /// fills between each line and zero
+    Zero,
+    /// fills between each line and the bottom of the y axis
+    AxisMin,
+    /// stacks the series on top of each other and fills between them.
+    /// Series are stacked point by point, so they should share their x values
+    Stacked,
+}
+
+/// How the hovered point is picked from the pointer position
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HoverMode {
+    /// the point closest to the pointer horizontally, of the line closest to it vertically
+    #[default]
+    X,
+    /// the point closest to the pointer
+    Nearest,
+}
+
+/// Point of a [`LineChart`] under the pointer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HoveredPoint {
+    /// index of the series, in the order of the legend
+    pub series: usize,
+    /// index of the point within its series
+    pub index: usize,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Series and index of the point in `lines` that is closest to `target`.
+///
+/// Positions are in percent of a chart of `size` px, distances are measured in px.
+/// Points with non-finite positions are skipped.
+fn nearest_point(
+    lines: &[Vec<(f64, f64)>],
+    target: (f64, f64),
+    size: (f64, f64),
+    mode: HoverMode,
+) -> Option<(usize, usize)> {
+    let distance = |(x, y): (f64, f64)| {
+        let dx = (x - target.0) * size.0 / 100.0;
+        let dy = (y - target.1) * size.1 / 100.0;
+        match mode {
+            HoverMode::X => (dx.abs(), dy.abs()),
+            HoverMode::Nearest => (dx.hypot(dy), 0.0),
+        }
+    };
+    lines
+        .iter()
+        .enumerate()
+        .flat_map(|(i, line)| line.iter().enumerate().map(move |(j, p)| (i, j, *p)))
+        .filter(|(_, _, (x, y))| x.is_finite() && y.is_finite())
+        .min_by(|(_, _, a), (_, _, b)| {
+            let (a, b) = (distance(*a), distance(*b));
+            a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+        })
+        .map(|(i, j, _)| (i, j))
+}
+
 pub struct LineChartOptions {
     pub max_ticks: u8,
     pub color: Box<dyn ChartColor>,
+    /// shows a legend with the series names if set
+    pub legend: Option<LegendOptions>,
+    pub fill: AreaFill,
+    /// scale of the y axis, logarithmic scales fall back to linear ones if there are
+    /// values <= 0. Areas on logarithmic scales are filled down to the bottom of the axis
+    pub scale: Scale,
+    /// scale of the x axis, e.g. [`Scale::Time`] for x values that are timestamps
+    pub x_scale: Scale,
+    /// range of the y axis
+    pub domain: Domain,
+    /// range of the x axis
+    pub x_domain: Domain,
+    /// draws gridlines behind the data if set
+    pub grid: Option<GridOptions>,
+    /// adds unlabeled ticks between the ticks of linear axes, logarithmic axes always
+    /// have them
+    pub minor_ticks: bool,
+    /// labels of the y axis
+    pub format: Box<dyn TickFormat>,
+    /// labels of the x axis, ignored for [`Scale::Time`] which always shows dates and times
+    pub x_format: Box<dyn TickFormat>,
+    pub locale: Locale,
+    pub titles: TitleOptions,
+    /// margins around the plot and sizes of the axes
+    pub layout: LayoutOptions,
+    /// axis on the right for the series it names, e.g. for a metric in another unit
+    pub secondary: Option<SecondaryAxis>,
+    /// highlights the point under the pointer with a marker and a crosshair if set
+    pub hover: Option<HoverMode>,
+    /// shows details of the hovered point if set, requires `hover`
+    pub tooltip: Option<TooltipOptions>,
+    /// zooms with the mouse wheel or pinch gestures and pans by dragging if set
+    pub zoom: Option<ZoomOptions>,
+    /// selects points by dragging if set, instead of panning
+    pub brush: Option<BrushOptions>,
+    /// selects the hovered point when it's clicked and marks it until it's clicked again,
+    /// requires `hover`
+    pub selectable: bool,
+    pub accessibility: AccessibilityOptions,
 }
 
 impl Default for LineChartOptions {
     fn default() -> Self {
         Self {
             max_ticks: 5u8,
-            color: Box::new(Palette(vec![Color::Hex("#dd3333")])),
+            color: Box::new(Palette(
+                iter::once(Color::Hex("#dd3333"))
+                    .chain(CATPPUCCIN_COLORS.iter().cloned())
+                    .collect(),
+            )),
+            legend: None,
+            fill: AreaFill::default(),
+            scale: Scale::default(),
+            x_scale: Scale::default(),
+            domain: Domain::default(),
+            x_domain: Domain::default(),
+            grid: None,
+            minor_ticks: false,
+            format: Box::new(Plain),
+            x_format: Box::new(Plain),
+            locale: Locale::default(),
+            titles: TitleOptions::default(),
+            layout: LayoutOptions::default(),
+            secondary: None,
+            hover: Some(HoverMode::default()),
+            tooltip: Some(TooltipOptions::default()),
+            zoom: None,
+            brush: None,
+            selectable: false,
+            accessibility: AccessibilityOptions::default(),
         }
     }
 }
 
+/// Responsive line chart with one or more series sharing the same axes
+///
+/// Example:
+/// ```rust
+/// use leptos_charts::*;
+/// use leptos::*;
+///
+/// let requests = vec![(0, 12.0), (1, 15.5), (2, 9.0), (3, 20.1)];
+/// let errors = vec![(0, 1.0), (1, 2.5), (2, 0.5), (3, 4.0)];
+/// let data = vec![
+///     NamedSeries::new("requests", requests),
+///     NamedSeries::new("errors", errors),
+/// ];
+/// let options = Box::new(LineChartOptions {
+///     legend: Some(LegendOptions::default()),
+///     // errors are drawn against an axis of their own on the right
+///     secondary: Some(SecondaryAxis {
+///         series: vec!["errors".to_string()],
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// });
+/// # #[cfg(hydrate)]
+/// # {
+/// view!{
+/// <LineChart
+///     series=data.into()
+///     options=options
+///     attr:width="300"
+///     attr:height="200"
+/// />
+/// }
+/// # }
+/// # ;
+/// ```
+///
+/// Time series, with x values in seconds since the Unix epoch:
+/// ```rust
+/// use leptos_charts::*;
+/// use leptos::*;
+///
+/// let start = Timestamp::from_millis(1704067200000);
+/// let data: Vec<(Timestamp, f64)> = (0..48)
+///     .map(|i| (Timestamp(start.0 + i as f64 * 1800.0), (i % 7) as f64))
+///     .collect();
+/// let options = Box::new(LineChartOptions {
+///     x_scale: Scale::Time,
+///     ..Default::default()
+/// });
+/// # #[cfg(hydrate)]
+/// # {
+/// view!{
+/// <LineChart
+///     values=data.into()
+///     options=options
+///     attr:width="300"
+///     attr:height="200"
+/// />
+/// }
+/// # }
+/// # ;
+/// ```
+#[allow(clippy::boxed_local)]
 #[component]
 pub fn LineChart<T, U>(
+    /// points of a single line, for multiple lines use `series`
+    #[prop(optional)]
     values: MaybeSignal<Vec<(T, U)>>,
+    /// multiple named lines, drawn after the line in `values`
+    #[prop(optional)]
+    series: MaybeSignal<Vec<NamedSeries<(T, U)>>>,
     options: Box<LineChartOptions>,
+    /// name of the line in `values`, shown in the legend. Defaults to "Series 1"
+    #[prop(optional, into)]
+    label: Option<String>,
+    /// set to the point under the pointer, or `None` if there is none
+    #[prop(optional)]
+    hovered: Option<RwSignal<Option<HoveredPoint>>>,
+    /// visible part of the chart, `None` shows all data. Changed by zooming and panning,
+    /// and can be set to move the chart
+    #[prop(optional)]
+    viewport: Option<RwSignal<Option<Viewport>>>,
+    /// points selected with the brush, `None` if there is no selection
+    #[prop(optional)]
+    selection: Option<RwSignal<Option<Selection>>>,
+    /// called with the hovered point when the plot is clicked
+    #[prop(optional, into)]
+    on_click: Option<Callback<ChartItem>>,
+    /// the selected point, if `selectable` is set in the options
+    #[prop(optional)]
+    selected: Option<RwSignal<Option<ChartItem>>>,
     #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView
 where
     T: ToPrimitive + Clone + PartialOrd + 'static,
     U: ToPrimitive + Clone + PartialOrd + 'static,
 {
-    let values = create_memo(move |_| {
-        values
-            .get()
-            .into_iter()
-            .map(|(x, y)| (x.to_f64().unwrap(), y.to_f64().unwrap()))
-            .collect::<Vec<(f64, f64)>>()
-    });
-    let min_max = create_memo(move |_| {
-        values.get().iter().fold(
-            (
-                (f64::INFINITY, f64::NEG_INFINITY),
-                (f64::INFINITY, f64::NEG_INFINITY),
-            ),
-            |((acc_min_x, acc_max_x), (acc_min_y, acc_max_y)), (x, y)| {
+    let LineChartOptions {
+        max_ticks,
+        color,
+        legend,
+        fill,
+        scale,
+        x_scale,
+        domain,
+        x_domain,
+        grid,
+        minor_ticks: show_minor_ticks,
+        format,
+        x_format,
+        locale,
+        titles,
+        layout,
+        secondary,
+        hover,
+        tooltip,
+        zoom,
+        brush,
+        selectable,
+        accessibility,
+    } = *options;
+    let events = ItemEvents::new(on_click, selected, selectable);
+    let viewport = viewport.unwrap_or_else(|| create_rw_signal(None));
+    let selection = selection.unwrap_or_else(|| create_rw_signal(None));
+    let color: Rc<dyn ChartColor> = color.into();
+    let format: Rc<dyn TickFormat> = format.into();
+    let x_format: Rc<dyn TickFormat> = x_format.into();
+    let tooltip = tooltip.filter(|_| hover.is_some()).map(Rc::new);
+    let secondary = secondary.map(Rc::new);
+    let chart_id = utils::unique_id();
+    let series =
+        create_memo(move |_| utils::get_xy_series(values.get(), label.clone(), series.get()));
+    // whether each series is drawn against the secondary axis
+    let on_secondary = create_memo({
+        let secondary = secondary.clone();
+        move |_| {
+            series.with(|s| {
+                s.iter()
+                    .map(|s| secondary.as_ref().is_some_and(|a| a.contains(&s.name)))
+                    .collect::<Vec<bool>>()
+            })
+        }
+    });
+    // every point as (x, bottom of the area, line), a bottom of -inf means the axis minimum
+    let lines = create_memo(move |_| {
+        series.with(|series| match fill {
+            AreaFill::Stacked => {
+                let ys = series
+                    .iter()
+                    .map(|s| s.values.iter().map(|(_, y)| *y).collect())
+                    .collect::<Vec<Vec<f64>>>();
+                on_secondary
+                    .with(|secondary| utils::stack_series_per_axis(&ys, secondary, false))
+                    .into_iter()
+                    .zip(series)
+                    .map(|(spans, s)| {
+                        s.values
+                            .iter()
+                            .zip(spans)
+                            .map(|((x, _), (start, end))| (*x, start, end))
+                            .collect()
+                    })
+                    .collect::<Vec<Vec<(f64, f64, f64)>>>()
+            }
+            AreaFill::None | AreaFill::Zero | AreaFill::AxisMin => {
+                let bottom = if fill == AreaFill::Zero {
+                    0.0
+                } else {
+                    f64::NEG_INFINITY
+                };
+                series
+                    .iter()
+                    .map(|s| s.values.iter().map(|(x, y)| (*x, bottom, *y)).collect())
+                    .collect()
+            }
+        })
+    });
+    let num_series = create_memo(move |_| series.with(Vec::len));
+    let labels = create_memo(move |_| {
+        series.with(|s| s.iter().map(|s| s.name.clone()).collect::<Vec<String>>())
+    });
+    let svg_ref = create_node_ref::<Svg>();
+    let size = use_chart_size(svg_ref);
+    let pointer = use_pointer(svg_ref);
+    let title_region = create_memo({
+        let titles = titles.clone();
+        move |_| titles.remaining_area(PlotArea::FULL, size.get())
+    });
+    let axes_region = create_memo(move |_| {
+        let region = title_region.get();
+        legend
+            .map(|legend| labels.with(|l| legend.remaining_area(l, region, size.get())))
+            .unwrap_or(region)
+    });
+    // ticks of the primary or secondary axis, from the series drawn against it
+    let axis_ticks = move |secondary: bool, scale: Scale, domain: &Domain| {
+        let ys = series.with(|s| {
+            s.iter()
+                .zip(on_secondary.get())
+                .filter(|(_, on_secondary)| *on_secondary == secondary)
+                .flat_map(|(s, _)| s.values.iter().map(|(_, y)| *y))
+                .collect::<Vec<f64>>()
+        });
+        let scale = utils::checked_scale(scale, &ys);
+        lines.with(|lines| {
+            let ys = lines
+                .iter()
+                .zip(on_secondary.get())
+                .filter(|(_, on_secondary)| *on_secondary == secondary)
+                .flat_map(|(line, _)| line.iter().flat_map(|(_, bottom, y)| [*bottom, *y]))
+                .collect::<Vec<f64>>();
+            utils::scale_ticks(scale, domain, &ys, max_ticks)
+        })
+    };
+    // ticks of the part of an axis that is visible in the viewport
+    let visible_ticks = move |ticks: &TickSpacing, range: Option<(f64, f64)>| match range {
+        Some((min, max)) => {
+            utils::scale_ticks(ticks.scale, &Domain::fixed(min, max), &[], max_ticks)
+        }
+        None => ticks.clone(),
+    };
+    let full_tick_config = create_memo(move |_| axis_ticks(false, scale, &domain));
+    let tick_config = create_memo(move |_| {
+        let range = viewport.with(|v| v.and_then(|v| v.y));
+        full_tick_config.with(|t| visible_ticks(t, range))
+    });
+    let secondary_tick_config = create_memo({
+        let secondary = secondary.clone();
+        move |_| {
+            secondary
+                .as_ref()
+                .map(|axis| axis_ticks(true, axis.scale, &axis.domain))
+        }
+    });
+    let secondary_ticks = create_memo({
+        let secondary = secondary.clone();
+        move |_| {
+            secondary_tick_config.with(|t| match (t, secondary.as_ref()) {
+                (Some(t), Some(axis)) => utils::get_ticks(t, axis.format.as_ref(), locale),
+                _ => vec![],
+            })
+        }
+    });
+    let ticks = create_memo({
+        let format = format.clone();
+        move |_| tick_config.with(|t| utils::get_ticks(t, format.as_ref(), locale))
+    });
+    let minor_ticks =
+        create_memo(move |_| tick_config.with(|t| utils::get_minor_ticks(t, show_minor_ticks)));
+    let full_x_tick_config = create_memo(move |_| {
+        let xs = series.with(|s| {
+            s.iter()
+                .flat_map(|s| s.values.iter().map(|(x, _)| *x))
+                .collect::<Vec<f64>>()
+        });
+        utils::scale_ticks(
+            utils::checked_scale(x_scale, &xs),
+            &x_domain,
+            &xs,
+            max_ticks,
+        )
+    });
+    let x_tick_config = create_memo(move |_| {
+        let range = viewport.with(|v| v.map(|v| v.x));
+        full_x_tick_config.with(|t| visible_ticks(t, range))
+    });
+    let x_ticks = create_memo({
+        let x_format = x_format.clone();
+        move |_| x_tick_config.with(|t| utils::get_x_ticks(t, x_format.as_ref(), locale))
+    });
+    let x_minor_ticks =
+        create_memo(move |_| x_tick_config.with(|t| utils::get_x_minor_ticks(t, show_minor_ticks)));
+    let plot_area = create_memo({
+        let titles = titles.clone();
+        move |_| {
+            let region = titles.axes_area(axes_region.get(), size.get());
+            ticks.with(|y| {
+                x_ticks.with(|x| {
+                    secondary_ticks
+                        .with(|secondary| layout.plot_area(region, size.get(), y, x, secondary))
+                })
+            })
+        }
+    });
+    // where every point is drawn in percent of the chart, NaN for points outside of the plot
+    let positions = create_memo(move |_| {
+        let plot = plot_area.get();
+        lines.with(|lines| {
+            x_tick_config.with(|x_ticks| {
+                lines
+                    .iter()
+                    .enumerate()
+                    .map(|(i, line)| {
+                        let y_ticks = secondary_tick_config
+                            .get()
+                            .filter(|_| on_secondary.with(|s| s[i]))
+                            .unwrap_or_else(|| tick_config.get());
+                        line.iter()
+                            .map(|(x, _, y)| {
+                                let x = x_ticks.position(*x);
+                                let y = y_ticks.point_position(*y);
+                                if (0.0..=100.0).contains(&x) && (0.0..=100.0).contains(&y) {
+                                    (plot.x_at(x), plot.y_at(100.0 - y))
+                                } else {
+                                    (f64::NAN, f64::NAN)
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect::<Vec<Vec<(f64, f64)>>>()
+            })
+        })
+    });
+    let hover_el = create_node_ref::<Rect>();
+    let is_hovering = use_element_hover(hover_el);
+    // point navigated to with the keyboard while the plot is focused
+    let keyboard_point = create_rw_signal(None::<(usize, usize)>);
+    // the point may not exist anymore once series are removed
+    create_effect(move |_| {
+        num_series.track();
+        keyboard_point.set(None);
+    });
+    // client position where the pointer was pressed, clicks that end a drag are ignored
+    let pressed_at = store_value(None::<(f64, f64)>);
+    if let Some(zoom) = zoom {
+        let range = |t: &TickSpacing| (t.min_point, t.max_point);
+        use_zoom(
+            hover_el,
+            viewport,
+            Signal::derive(move || Viewport {
+                x: full_x_tick_config.with(range),
+                y: Some(full_tick_config.with(range)),
+            }),
+            Signal::derive(move || {
                 (
-                    (f64::min(acc_min_x, *x), f64::max(acc_max_x, *x)),
-                    (f64::min(acc_min_y, *y), f64::max(acc_max_y, *y)),
+                    full_x_tick_config.with(|t| t.scale),
+                    full_tick_config.with(|t| t.scale),
                 )
-            },
+            }),
+            zoom,
+            brush.is_none(),
+        );
+    }
+    let dragged = brush.as_ref().map(|brush| {
+        let mode = brush.mode;
+        use_brush(svg_ref, plot_area.into(), mode, move |area| {
+            let selected = area.map(|area| {
+                let plot = plot_area.get_untracked();
+                let indices = positions.with_untracked(|lines| {
+                    lines
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(i, line)| {
+                            line.iter()
+                                .enumerate()
+                                .filter(|(_, (x, y))| {
+                                    area.contains((
+                                        100.0 * (x - plot.x) / plot.width,
+                                        100.0 * (y - plot.y) / plot.height,
+                                    ))
+                                })
+                                .map(move |(j, _)| (i, j))
+                        })
+                        .collect()
+                });
+                Selection {
+                    x: x_tick_config
+                        .with_untracked(|t| (t.value_at(area.x.0), t.value_at(area.x.1))),
+                    y: (mode == BrushMode::XY).then(|| {
+                        tick_config.with_untracked(|t| {
+                            (t.value_at(100.0 - area.y.1), t.value_at(100.0 - area.y.0))
+                        })
+                    }),
+                    indices,
+                }
+            });
+            selection.set(selected);
+        })
+    });
+    // selection in percent of the plot, while dragging or after
+    let selected_area = Signal::derive(move || {
+        dragged.and_then(|dragged| dragged.get()).or_else(|| {
+            let clamp = |(a, b): (f64, f64)| (a.clamp(0.0, 100.0), b.clamp(0.0, 100.0));
+            selection.get().map(|s| BrushArea {
+                x: x_tick_config.with(|t| clamp((t.position(s.x.0), t.position(s.x.1)))),
+                y: s.y
+                    .map(|(min, max)| {
+                        tick_config
+                            .with(|t| clamp((100.0 - t.position(max), 100.0 - t.position(min))))
+                    })
+                    .unwrap_or((0.0, 100.0)),
+            })
+        })
+    });
+    let hovered_point = create_memo(move |_| {
+        let (i, j) = match keyboard_point.get() {
+            Some(point) => point,
+            None => {
+                let mode = hover.filter(|_| is_hovering.get())?;
+                let (x, y) = pointer.get();
+                let (width, height) = size.get();
+                let target = (100.0 * x / width, 100.0 * y / height);
+                positions.with(|p| nearest_point(p, target, (width, height), mode))?
+            }
+        };
+        series.with(|s| {
+            let (x, y) = s.get(i)?.values.get(j).copied()?;
+            Some(HoveredPoint {
+                series: i,
+                index: j,
+                x,
+                y,
+            })
+        })
+    });
+    if let Some(hovered) = hovered {
+        create_effect(move |_| hovered.set(hovered_point.get()));
+    }
+    // position and color of the marker on the hovered point
+    let marker = create_memo({
+        let color = color.clone();
+        move |_| {
+            let point = hovered_point.get()?;
+            let (x, y) = positions.with(|p| p.get(point.series)?.get(point.index).copied())?;
+            let color = String::from(color.color_for_index(point.series, num_series.get()));
+            Some((x, y, color))
+        }
+    });
+    let selected_position = create_memo(move |_| {
+        let item = events.selected()?;
+        positions
+            .with(|p| p.get(item.series)?.get(item.index).copied())
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+    });
+    let activate = move || {
+        if let Some(point) = hovered_point.get_untracked() {
+            events.activate(ChartItem {
+                series: point.series,
+                index: point.index,
+                value: point.y,
+                label: labels.with_untracked(|l| l[point.series].clone()),
+            });
+        }
+    };
+    let on_key = move |ev: KeyboardEvent| {
+        let key = ev.key();
+        if is_activation(&key) {
+            ev.prevent_default();
+            activate();
+            return;
+        }
+        let lengths = lines.with_untracked(|l| l.iter().map(Vec::len).collect::<Vec<_>>());
+        // the first navigation key enters keyboard mode at the first point, so focusing the
+        // plot with a click keeps following the pointer
+        let next = match keyboard_point.get_untracked() {
+            Some(point) => next_point(&key, point, &lengths),
+            None => {
+                first_point(&lengths).filter(|start| next_point(&key, *start, &lengths).is_some())
+            }
+        };
+        if let Some(next) = next {
+            ev.prevent_default();
+            keyboard_point.set(Some(next));
+        }
+    };
+    // the tooltip is shown at the point navigated to with the keyboard instead of the pointer
+    let tooltip_position = Signal::derive(move || {
+        keyboard_point
+            .get()
+            .and_then(|(i, j)| positions.with(|p| p.get(i)?.get(j).copied()))
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .map(|(x, y)| {
+                let (width, height) = size.get();
+                (x * width / 100.0, y * height / 100.0)
+            })
+            .unwrap_or_else(|| pointer.get())
+    });
+    // what the tooltip shows and screen readers read for point `(x, y)` of a series
+    let point_data = {
+        let color = color.clone();
+        let secondary = secondary.clone();
+        move |series: usize, (x, y): (f64, f64)| {
+            let value = match secondary
+                .as_ref()
+                .filter(|_| on_secondary.with(|s| s[series]))
+            {
+                Some(axis) => axis.format.format_localized(y, locale),
+                None => format.format_localized(y, locale),
+            };
+            TooltipData {
+                name: labels.with(|l| l[series].clone()),
+                x: Some(x_tick_config.with(|t| t.value_label(x, x_format.as_ref(), locale))),
+                value,
+                color: String::from(color.color_for_index(series, num_series.get())),
+            }
+        }
+    };
+    let tooltip_data = Signal::derive({
+        let point_data = point_data.clone();
+        move || {
+            let point = hovered_point.get()?;
+            Some(point_data(point.series, (point.x, point.y)))
+        }
+    });
+    let label = accessibility.label_for(&titles, "Line chart");
+    let description = accessibility.description_or(move || {
+        format!(
+            "{} of up to {}: {}",
+            count(num_series.get(), "series", "series"),
+            count(
+                series.with(|s| s.iter().map(|s| s.values.len()).max().unwrap_or(0)),
+                "point",
+                "points"
+            ),
+            labels.get().join(", ")
         )
     });
-    let max_ticks = options.max_ticks;
-    let tick_config =
-        create_memo(move |_| utils::nice_ticks(min_max.get().1 .0, min_max.get().1 .1, max_ticks));
-    let ticks = create_memo(move |_| tick_config.with(utils::get_ticks));
     view! {
-        <svg {..attrs}>
-            <YAxis ticks=ticks/>
+        <svg role="figure" {..attrs} _ref=svg_ref>
+            <title>{label.clone()}</title>
+            <desc>{description}</desc>
+            {grid
+                .map(|grid| {
+                    view! {
+                        <Grid
+                            horizontal=Signal::derive(move || {
+                                ticks.with(|t| t.iter().map(|t| t.0).collect())
+                            })
+
+                            vertical=Signal::derive(move || {
+                                x_ticks.with(|t| t.iter().map(|t| t.0).collect())
+                            })
+
+                            horizontal_minor=Signal::derive(move || {
+                                tick_config.with(|t| utils::get_minor_ticks(t, true))
+                            })
+
+                            vertical_minor=Signal::derive(move || {
+                                x_tick_config.with(|t| utils::get_x_minor_ticks(t, true))
+                            })
+
+                            options=grid
+                            area=plot_area
+                        />
+                    }
+                })}
+            <YAxis
+                ticks=ticks
+                minor_ticks=minor_ticks
+                area=plot_area
+                size=size
+                layout=layout
+            />
+            {secondary
+                .is_some()
+                .then(|| {
+                    view! {
+                        <YAxis
+                            ticks=secondary_ticks
+                            minor_ticks=Signal::derive(move || {
+                                secondary_tick_config
+                                    .with(|t| {
+                                        t.as_ref()
+                                            .map(|t| utils::get_minor_ticks(t, show_minor_ticks))
+                                            .unwrap_or_default()
+                                    })
+                            })
+
+                            area=plot_area
+                            size=size
+                            layout=layout
+                            side=AxisSide::Right
+                        />
+                    }
+                })}
+            <XAxis
+                ticks=x_ticks
+                minor_ticks=x_minor_ticks
+                area=plot_area
+                size=size
+                layout=layout
+            />
+            <Titles
+                options=titles
+                region=title_region
+                axes_region=axes_region
+                plot=plot_area
+                size=size
+            />
+            {legend
+                .map(|legend| {
+                    view! {
+                        <Legend
+                            labels=labels
+                            color=color.clone()
+                            options=legend
+                            region=move || legend.region(title_region.get(), plot_area.get())
+                            size=size
+                        />
+                    }
+                })}
             <svg
-                x="10%"
-                y="10%"
-                width="90%"
-                height="80%"
+                x=move || format!("{}%", plot_area.get().x)
+                y=move || format!("{}%", plot_area.get().y)
+                width=move || format!("{}%", plot_area.get().width)
+                height=move || format!("{}%", plot_area.get().height)
                 viewBox="0 0 100 100"
                 preserveAspectRatio="none"
             >
                 <g transform="matrix(1 0 0 -1 0 100)">
-                    <defs>
-                        <linearGradient id="gradient" x1="0%" y1="0%" x2="0%" y2="100%">
-                            <stop
-                                offset="0%"
-                                stop-color=String::from(options.color.color_for_index(0, 2))
-                            ></stop>
-                            <stop
-                                offset="100%"
-                                stop-color=String::from(options.color.color_for_index(1, 2))
-                            ></stop>
-                        </linearGradient>
-                    </defs>
-                    <polyline
-                        fill="none"
-                        style="stroke:url(#gradient)"
-                        stroke-width="1"
-                        vector-effect="non-scaling-stroke"
-                        stroke-linejoin="round"
-                        points=move || {
-                            values
-                                .get()
-                                .into_iter()
-                                .map(|(x, y)| (
-                                    100.0 * (x - min_max.get().0.0)
-                                        / (min_max.get().0.1 - min_max.get().0.0),
-                                    100.0 * (y - tick_config.get().min_point)
-                                        / (tick_config.get().max_point
-                                            - tick_config.get().min_point),
-                                ))
-                                .map(|(x, y)| format!("{},{}", x, y))
-                                .intersperse(" ".to_string())
-                                .collect::<String>()
-                        }
-                    >
-                    </polyline>
+                    {move || {
+                        lines
+                            .get()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, line)| {
+                                let color = String::from(color.color_for_index(i, num_series.get()));
+                                let gradient_id = format!("area-gradient-{}-{}", chart_id, i);
+                                let secondary_line = on_secondary.with(|s| s[i]);
+                                let to_plot = move |x: f64, y: f64| {
+                                    let x_ticks = x_tick_config.get();
+                                    let y_ticks = secondary_tick_config
+                                        .get()
+                                        .filter(|_| secondary_line)
+                                        .unwrap_or_else(|| tick_config.get());
+                                    format!(
+                                        "{},{}",
+                                        x_ticks.position(x),
+                                        y_ticks.point_position(y),
+                                    )
+                                };
+                                let area = (fill != AreaFill::None)
+                                    .then(|| {
+                                        let line = line.clone();
+                                        view! {
+                                            // the y axis is flipped, so the gradient runs from the
+                                            // bottom of the area to the top
+                                            <linearGradient
+                                                id=gradient_id.clone()
+                                                x1="0"
+                                                y1="0"
+                                                x2="0"
+                                                y2="1"
+                                            >
+                                                <stop
+                                                    offset="0%"
+                                                    stop-color=color.clone()
+                                                    stop-opacity="0"
+                                                ></stop>
+                                                <stop
+                                                    offset="100%"
+                                                    stop-color=color.clone()
+                                                    stop-opacity="0.6"
+                                                ></stop>
+                                            </linearGradient>
+                                            <polygon
+                                                fill=format!("url(#{})", gradient_id)
+                                                stroke="none"
+                                                points=move || {
+                                                    line.iter()
+                                                        .map(|(x, _, y)| to_plot(*x, *y))
+                                                        .chain(
+                                                            line
+                                                                .iter()
+                                                                .rev()
+                                                                .map(|(x, bottom, _)| to_plot(*x, *bottom)),
+                                                        )
+                                                        .join(" ")
+                                                }
+                                            >
+                                            </polygon>
+                                        }
+                                    });
+                                view! {
+                                    {area}
+                                    <polyline
+                                        fill="none"
+                                        stroke=color
+                                        stroke-width="1"
+                                        vector-effect="non-scaling-stroke"
+                                        stroke-linejoin="round"
+                                        points=move || {
+                                            line.iter().map(|(x, _, y)| to_plot(*x, *y)).join(" ")
+                                        }
+                                    >
+                                    </polyline>
+                                }
+                            })
+                            .collect_view()
+                    }}
 
                 </g>
             </svg>
+            {brush
+                .map(|brush| {
+                    view! { <Brush selected=selected_area area=plot_area color=brush.color/> }
+                })}
+            {move || {
+                selected_position
+                    .get()
+                    .map(|(x, y)| {
+                        view! {
+                            <circle
+                                cx=format!("{}%", x)
+                                cy=format!("{}%", y)
+                                r="6"
+                                fill="none"
+                                stroke="#000"
+                                stroke-width="2px"
+                                pointer-events="none"
+                            ></circle>
+                        }
+                    })
+            }}
+            {hover
+                .map(|_| {
+                    view! {
+                        <g pointer-events="none">
+                            {move || {
+                                marker
+                                    .get()
+                                    .map(|(x, y, color)| {
+                                        let plot = plot_area.get();
+                                        view! {
+                                            <line
+                                                x1=format!("{}%", x)
+                                                y1=format!("{}%", plot.y)
+                                                x2=format!("{}%", x)
+                                                y2=format!("{}%", plot.bottom())
+                                                stroke="#888"
+                                                stroke-width="1px"
+                                                stroke-dasharray="4 4"
+                                                vector-effect="non-scaling-stroke"
+                                            ></line>
+                                            <circle
+                                                cx=format!("{}%", x)
+                                                cy=format!("{}%", y)
+                                                r="4"
+                                                fill=color
+                                                stroke="white"
+                                                stroke-width="2px"
+                                            ></circle>
+                                        }
+                                    })
+                            }}
+
+                        </g>
+                    }
+                })}
+            {(hover.is_some() || zoom.is_some())
+                .then(|| {
+                    view! {
+                        <rect
+                            node_ref=hover_el
+                            x=move || format!("{}%", plot_area.get().x)
+                            y=move || format!("{}%", plot_area.get().y)
+                            width=move || format!("{}%", plot_area.get().width)
+                            height=move || format!("{}%", plot_area.get().height)
+                            fill="transparent"
+                            style=zoom.map(|_| "touch-action: none; cursor: grab")
+                            tabindex=hover.map(|_| "0")
+                            role=hover.map(|_| "application")
+                            aria-label=move || {
+                                hover?;
+                                Some(
+                                    tooltip_data
+                                        .get()
+                                        .filter(|_| keyboard_point.with(Option::is_some))
+                                        .map(|data| data.aria_label())
+                                        .unwrap_or_else(|| {
+                                            "Plot, use the arrow keys to read the values".to_string()
+                                        }),
+                                )
+                            }
+
+                            on:pointerdown=move |e: PointerEvent| {
+                                pressed_at
+                                    .set_value(Some((e.client_x() as f64, e.client_y() as f64)));
+                            }
+
+                            on:pointermove=move |_| {
+                                if keyboard_point.with_untracked(Option::is_some) {
+                                    keyboard_point.set(None);
+                                }
+                            }
+
+                            on:click=move |e: MouseEvent| {
+                                let dragged = pressed_at
+                                    .get_value()
+                                    .is_some_and(|(x, y)| {
+                                        (e.client_x() as f64 - x).hypot(e.client_y() as f64 - y)
+                                            >= CLICK_DISTANCE
+                                    });
+                                if !dragged {
+                                    activate();
+                                }
+                            }
+
+                            on:keydown=on_key
+                            on:blur=move |_| keyboard_point.set(None)
+                        ></rect>
+                    }
+                })}
+            {zoom
+                .filter(|zoom| zoom.reset_button)
+                .map(|_| view! { <ZoomReset viewport=viewport area=plot_area/> })}
+            {tooltip
+                .map(|options| {
+                    view! {
+                        <Tooltip
+                            data=tooltip_data
+                            pointer=tooltip_position
+                            size=size
+                            options=options
+                        />
+                    }
+                })}
+            {accessibility
+                .data_table
+                .then(|| {
+                    let rows = Signal::derive(move || {
+                        series
+                            .with(|series| {
+                                series
+                                    .iter()
+                                    .enumerate()
+                                    .flat_map(|(i, s)| s.values.iter().map(move |p| (i, *p)))
+                                    .map(|(i, p)| point_data(i, p))
+                                    .collect::<Vec<TooltipData>>()
+                            })
+                    });
+                    view! { <DataTable caption=label headers=["Series", "X", "Value"] rows=rows/> }
+                })}
+
         </svg>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_points() {
+        let lines = vec![
+            vec![(10.0, 50.0), (20.0, 40.0), (30.0, 60.0)],
+            vec![(10.0, 20.0), (20.0, 10.0), (f64::NAN, f64::NAN)],
+        ];
+        let size = (200.0, 100.0);
+        // the closest x, then the closest line at that x
+        assert_eq!(
+            nearest_point(&lines, (19.0, 15.0), size, HoverMode::X),
+            Some((1, 1))
+        );
+        assert_eq!(
+            nearest_point(&lines, (29.0, 15.0), size, HoverMode::X),
+            Some((0, 2))
+        );
+        // 2px to the right of the first point is closer than 4px below it
+        assert_eq!(
+            nearest_point(&lines, (11.0, 24.0), size, HoverMode::Nearest),
+            Some((1, 0))
+        );
+        assert_eq!(
+            nearest_point(&[], (11.0, 24.0), size, HoverMode::Nearest),
+            None
+        );
+    }
+}
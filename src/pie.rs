@@ -1,28 +1,69 @@
 use leptos::{svg::*, *};
 use leptos_use::*;
 use num_traits::ToPrimitive;
-use std::{f64::consts::TAU, iter};
+use std::{f64::consts::TAU, iter, rc::Rc};
 
-use crate::{point::Series, ChartColor, Palette, Point, CATPPUCCIN_COLORS};
+use crate::{
+    accessibility::{count, AccessibilityOptions, DataTable},
+    focus::{center_in, is_focus_visible, FocusNavigation},
+    format::{Locale, Percent, Plain, TickFormat},
+    item::{ChartItem, ItemEvents},
+    layout::{use_chart_size, PlotArea},
+    legend::{Legend, LegendOptions},
+    point::Series,
+    title::{TitleOptions, Titles},
+    tooltip::{show_on_hover, use_pointer, Tooltip, TooltipData, TooltipOptions},
+    ChartColor, Palette, Point, CATPPUCCIN_COLORS,
+};
 
 pub struct PieChartOptions {
     pub color: Box<dyn ChartColor>,
+    /// shows a legend for the segment labels if set
+    pub legend: Option<LegendOptions>,
+    /// radius of the hole in the middle as a fraction of the outer radius,
+    /// values above 0 turn the pie into a donut
+    pub inner_radius: f64,
+    /// shows the sum of all values in the center, unless children are passed
+    pub show_total: bool,
+    /// values of hovered segments and the total
+    pub format: Box<dyn TickFormat>,
+    pub locale: Locale,
+    /// title and subtitle above the pie, axis titles are ignored
+    pub titles: TitleOptions,
+    /// shows the label, value and share of hovered segments if set
+    pub tooltip: Option<TooltipOptions>,
+    /// selects a segment when it's clicked and highlights it until it's clicked again
+    pub selectable: bool,
+    pub accessibility: AccessibilityOptions,
 }
 
 impl Default for PieChartOptions {
     fn default() -> Self {
         Self {
             color: Box::new(Palette(CATPPUCCIN_COLORS.clone())),
+            legend: None,
+            inner_radius: 0.0,
+            show_total: false,
+            format: Box::new(Plain),
+            locale: Locale::default(),
+            titles: TitleOptions::default(),
+            tooltip: Some(TooltipOptions::default()),
+            selectable: false,
+            accessibility: AccessibilityOptions::default(),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 struct PieSegment {
+    /// index of the value, including values that aren't drawn
+    index: usize,
     from: (f64, f64),
     to: (f64, f64),
     value: f64,
     label: String,
+    /// formatted value and share of the segment
+    value_label: String,
 }
 enum SegmentSize {
     LessThanHalf,
@@ -40,7 +81,9 @@ impl PieSegment {
             SegmentSize::MoreThanHalf
         }
     }
-    fn get_arc_path(&self) -> String {
+    /// Path of the segment, a wedge from the origin for an `inner_radius` of 0,
+    /// a ring segment otherwise
+    fn get_arc_path(&self, inner_radius: f64) -> String {
         let angle = self.angle();
 
         let large_arc_flag = match angle {
@@ -48,44 +91,33 @@ impl PieSegment {
             SegmentSize::MoreThanHalf => 1,
         };
 
+        if inner_radius <= 0.0 {
+            return format!(
+                "M0 0 {from_x} {from_y} A100 100 0 {arc_flag} 1 {to_x} {to_y}Z",
+                from_x = self.from.0,
+                from_y = self.from.1,
+                to_x = self.to.0,
+                to_y = self.to.1,
+                arc_flag = large_arc_flag
+            );
+        }
+
         format!(
-            "M0 0 {from_x} {from_y} A100 100 0 {arc_flag} 1 {to_x} {to_y}Z",
+            "M{from_x} {from_y} A100 100 0 {arc_flag} 1 {to_x} {to_y} \
+             L{inner_to_x} {inner_to_y} \
+             A{inner} {inner} 0 {arc_flag} 0 {inner_from_x} {inner_from_y}Z",
             from_x = self.from.0,
             from_y = self.from.1,
             to_x = self.to.0,
             to_y = self.to.1,
+            inner_to_x = self.to.0 * inner_radius,
+            inner_to_y = self.to.1 * inner_radius,
+            inner_from_x = self.from.0 * inner_radius,
+            inner_from_y = self.from.1 * inner_radius,
+            inner = 100.0 * inner_radius,
             arc_flag = large_arc_flag
         )
     }
-
-    // Gets a middle vector for two vectors in a circle segment
-    // This points in the direction of a circle segment's center
-    // even if the angle of the segment is >= 180°
-    // uses the cross product to figure out the angle and flips the vector
-    // if it's larger than 180°. For the 180° case, it creates a new vector
-    // 90° clockwise perpendicular to the from vector.
-    fn get_center_unit_vector(&self) -> (f64, f64) {
-        match self.angle() {
-            SegmentSize::Half => {
-                let magnitude = f64::sqrt(self.from.0.powi(2) + self.from.1.powi(2));
-                (self.from.1 / magnitude, -self.from.0 / magnitude)
-            }
-            SegmentSize::LessThanHalf => {
-                let new_x = (self.from.0 + self.to.0) / 2.0;
-                let new_y = (self.from.1 + self.to.1) / 2.0;
-                let magnitude = f64::sqrt(new_x.powi(2) + new_y.powi(2));
-
-                (new_x / magnitude, new_y / magnitude)
-            }
-            SegmentSize::MoreThanHalf => {
-                let new_x = (self.from.0 + self.to.0) / 2.0;
-                let new_y = (self.from.1 + self.to.1) / 2.0;
-                let magnitude = f64::sqrt(new_x.powi(2) + new_y.powi(2));
-
-                (-new_x / magnitude, -new_y / magnitude)
-            }
-        }
-    }
 }
 
 /// Simple Pie chart.
@@ -98,6 +130,7 @@ impl PieSegment {
 /// let data: Vec<f64> = vec![2.0, 3.0, 1.5, 7.0, 1.0, 2.5, 9.9];
 /// let options = Box::new(PieChartOptions {
 ///     color: Box::new(Palette(CATPPUCCIN_COLORS.clone())),
+///     ..Default::default()
 /// });
 ///
 /// # #[cfg(hydrate)]
@@ -113,15 +146,52 @@ impl PieSegment {
 /// # }
 /// # ;
 /// ```
+#[allow(clippy::boxed_local)]
 #[component]
 pub fn PieChart<T>(
     values: MaybeSignal<Series<T>>,
     options: Box<PieChartOptions>,
+    /// content for the center of a donut, rendered in an svg with the origin at the center
+    /// and a pie radius of 100
+    #[prop(optional)]
+    children: Option<Children>,
+    /// called with the segment that is clicked
+    #[prop(optional, into)]
+    on_click: Option<Callback<ChartItem>>,
+    /// the selected segment, if `selectable` is set in the options
+    #[prop(optional)]
+    selected: Option<RwSignal<Option<ChartItem>>>,
     #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView
 where
     T: ToPrimitive + Clone + PartialOrd + 'static,
 {
+    let PieChartOptions {
+        color,
+        legend,
+        inner_radius,
+        show_total,
+        format,
+        locale,
+        titles,
+        tooltip,
+        selectable,
+        accessibility,
+    } = *options;
+    let events = ItemEvents::new(on_click, selected, selectable);
+    let titles = TitleOptions {
+        x_title: None,
+        y_title: None,
+        ..titles
+    };
+    let format: Rc<dyn TickFormat> = format.into();
+    let inner_radius = inner_radius.clamp(0.0, 1.0);
+    let color: Rc<dyn ChartColor> = color.into();
+    let tooltip = tooltip.map(Rc::new);
+    let hovered = create_rw_signal(None::<TooltipData>);
+    let svg_ref = create_node_ref::<Svg>();
+    let size = use_chart_size(svg_ref);
+    let pointer = use_pointer(svg_ref);
     let values = create_memo(move |_| {
         values
             .get()
@@ -130,56 +200,164 @@ where
                 value: p.value.to_f64().unwrap(),
                 label: p.label.clone(),
             })
-            .filter(|v| v.value > 0.0)
-            .collect::<Vec<Point<f64>>>()
+            .enumerate()
+            .filter(|(_, v)| v.value > 0.0)
+            .collect::<Vec<(usize, Point<f64>)>>()
     });
     let num_pies = create_memo(move |_| values.get().len());
-    let sum = create_memo(move |_| values.get().iter().map(|v| v.value).sum::<f64>());
+    let focus = FocusNavigation::new(num_pies.into());
+    // center of the focused segment in px, the tooltip is shown there instead of at the pointer
+    let focus_anchor = create_rw_signal(None::<(f64, f64)>);
+    let tooltip_position =
+        Signal::derive(move || focus_anchor.get().unwrap_or_else(|| pointer.get()));
+    let labels = create_memo(move |_| {
+        values
+            .get()
+            .into_iter()
+            .map(|(_, p)| p.label)
+            .collect::<Vec<String>>()
+    });
+    let title_region = create_memo({
+        let titles = titles.clone();
+        move |_| titles.remaining_area(PlotArea::FULL, size.get())
+    });
+    let plot_area = create_memo(move |_| {
+        let region = title_region.get();
+        legend
+            .map(|legend| labels.with(|l| legend.remaining_area(l, region, size.get())))
+            .unwrap_or(region)
+    });
+    let sum = create_memo(move |_| values.get().iter().map(|(_, v)| v.value).sum::<f64>());
+    let segment_format = format.clone();
     let values = create_memo(move |_| {
-        iter::once((0.0, 99.0, 0.0, "".to_string()))
+        iter::once((0, 0.0, 99.0, 0.0, "".to_string(), "".to_string()))
             .chain(
                 values
                     .get()
                     .into_iter()
-                    .map(|f| (f.value, f.value / sum.get(), f.label))
-                    .scan((0.0, 0.0, "".to_string()), |state, v| {
-                        *state = (v.0, state.1 + v.1, format!("{}: {:.1}%", v.2, v.1 * 100.0));
-                        Some(state.clone())
+                    .map(|(i, f)| (i, f.value, f.value / sum.get(), f.label))
+                    .scan(0.0, |end, (i, value, share, label)| {
+                        *end += share;
+                        let value_label = format!(
+                            "{} ({})",
+                            segment_format.format_localized(value, locale),
+                            Percent {
+                                decimals: 1,
+                                fraction: true,
+                            }
+                            .format_localized(share, locale)
+                        );
+                        Some((i, value, *end, label, value_label))
                     })
-                    .map(|(f, v, l)| (f, (v * TAU).cos() * 99.0, (v * TAU).sin() * 99.0, l)),
+                    .map(|(i, f, v, l, vl)| {
+                        (i, f, (v * TAU).cos() * 99.0, (v * TAU).sin() * 99.0, l, vl)
+                    }),
             )
             .map_windows(|[from, to]| PieSegment {
-                from: (from.1, from.2),
-                to: (to.1, to.2),
-                value: to.0,
-                label: to.3.clone(),
+                index: to.0,
+                from: (from.2, from.3),
+                to: (to.2, to.3),
+                value: to.1,
+                label: to.4.clone(),
+                value_label: to.5.clone(),
             })
             .collect::<Vec<PieSegment>>()
     });
+    // what the tooltip shows and screen readers read for every segment
+    let segment_data = create_memo({
+        let color = color.clone();
+        move |_| {
+            values.with(|values| {
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, segment)| TooltipData {
+                        name: segment.label.clone(),
+                        x: None,
+                        value: segment.value_label.clone(),
+                        color: String::from(color.color_for_index(i, num_pies.get())),
+                    })
+                    .collect::<Vec<TooltipData>>()
+            })
+        }
+    });
+    let label = accessibility.label_for(&titles, "Pie chart");
+    let description = accessibility.description_or({
+        let format = format.clone();
+        move || {
+            format!(
+                "{}, total {}",
+                count(num_pies.get(), "segment", "segments"),
+                format.format_localized(sum.get(), locale)
+            )
+        }
+    });
 
     view! {
-        <svg {..attrs}>
+        <svg role="figure" {..attrs} _ref=svg_ref>
+            <title>{label.clone()}</title>
+            <desc>{description}</desc>
+            <Titles
+                options=titles
+                region=title_region
+                axes_region=plot_area
+                plot=plot_area
+                size=size
+            />
+            {legend
+                .map(|legend| {
+                    view! {
+                        <Legend
+                            labels=labels
+                            color=color.clone()
+                            options=legend
+                            region=move || legend.region(title_region.get(), plot_area.get())
+                            size=size
+                        />
+                    }
+                })}
             {move || {
                 values
                     .get()
                     .into_iter()
+                    .zip(segment_data.get())
                     .enumerate()
-                    .map(|(i, segment)| {
+                    .map(|(i, (segment, data))| {
                         let path_el = create_node_ref::<Path>();
-                        let text_el = create_node_ref::<Text>();
-                        let is_path_hovered = use_element_hover(path_el);
-                        let is_text_hovered = use_element_hover(text_el);
-                        let is_hovered = create_memo(move |_| {
-                            is_path_hovered.with(|&h| is_text_hovered.with(|&t| h || t))
-                        });
-                        let label_pos = segment.get_center_unit_vector();
-                        let color = String::from(options.color.color_for_index(i, num_pies.get()));
+                        let is_hovered = use_element_hover(path_el);
+                        let is_active =
+                            Signal::derive(move || is_hovered.get() || focus.is_focused(i));
+                        focus
+                            .register(
+                                i,
+                                move || {
+                                    if let Some(el) = path_el.get_untracked() {
+                                        let _ = el.focus();
+                                    }
+                                },
+                            );
+                        let color = data.color.clone();
+                        let item = ChartItem {
+                            series: 0,
+                            index: segment.index,
+                            value: segment.value,
+                            label: segment.label.clone(),
+                        };
+                        let is_selected = move || events.is_selected(0, segment.index);
+                        let aria_label = data.aria_label();
+                        show_on_hover(is_active, data, hovered);
                         view! {
-                            <svg viewBox="0 0 200 200">
+                            <svg
+                                x=move || format!("{}%", plot_area.get().x)
+                                y=move || format!("{}%", plot_area.get().y)
+                                width=move || format!("{}%", plot_area.get().width)
+                                height=move || format!("{}%", plot_area.get().height)
+                                viewBox="0 0 200 200"
+                            >
                                 <g transform="translate(100,100)" stroke="#000" stroke-width="1">
                                     <mask id=format!("cut-path-{}", i)>
                                         <path
-                                            d=segment.get_arc_path()
+                                            d=segment.get_arc_path(inner_radius)
                                             fill="white"
                                             stroke="black"
                                             stroke-width="2"
@@ -188,14 +366,45 @@ where
                                     </mask>
                                     <path
                                         node_ref=path_el
-                                        d=segment.get_arc_path()
+                                        d=segment.get_arc_path(inner_radius)
                                         fill=color.clone()
-                                        fill-opacity=0.6
-                                        stroke=color
+                                        fill-opacity=move || if is_selected() { "1" } else { "0.6" }
+                                        stroke=move || {
+                                            if is_selected() { "#000".to_string() } else { color.clone() }
+                                        }
+
                                         stroke-width="2"
                                         vector-effect="non-scaling-stroke"
+                                        cursor=events.is_active().then_some("pointer")
+                                        role=if events.is_active() { "button" } else { "img" }
+                                        aria-label=aria_label
+                                        tabindex=move || focus.tab_index(i)
+                                        on:click={
+                                            let item = item.clone();
+                                            move |_| events.activate(item.clone())
+                                        }
+
+                                        on:keydown=move |ev| {
+                                            if focus.on_key(&ev, i) {
+                                                events.activate(item.clone());
+                                            }
+                                        }
+
+                                        on:focus=move |_| {
+                                            let keyboard = is_focus_visible(path_el);
+                                            focus.on_focus(i, keyboard);
+                                            if keyboard {
+                                                focus_anchor.set(center_in(path_el, svg_ref));
+                                            }
+                                        }
+
+                                        on:blur=move |_| {
+                                            focus.on_blur(i);
+                                            focus_anchor.set(None);
+                                        }
+
                                         mask=move || {
-                                            if is_hovered.get() {
+                                            if is_active.get() || is_selected() {
                                                 "none".to_string()
                                             } else {
                                                 format!("url(#cut-path-{})", i)
@@ -203,30 +412,75 @@ where
                                         }
                                     >
                                     </path>
-                                    <Show when=move || is_hovered.get() fallback=|| ()>
-                                        <text
-                                            node_ref=text_el
-                                            font-size="15px"
-                                            vector-effect="non-scaling-stroke"
-                                            x=label_pos.0 * 85.0
-                                            y=label_pos.1 * 85.0
-                                        >
-                                            <tspan
-                                                text-anchor="middle"
-                                                dominant-baseline="middle"
-                                                color="#000"
-                                            >
-                                                {segment.label.clone()}
-                                            </tspan>
-                                        </text>
-                                    </Show>
                                 </g>
                             </svg>
                         }
                     })
                     .collect_view()
             }}
+            <svg
+                x=move || format!("{}%", plot_area.get().x)
+                y=move || format!("{}%", plot_area.get().y)
+                width=move || format!("{}%", plot_area.get().width)
+                height=move || format!("{}%", plot_area.get().height)
+                viewBox="-100 -100 200 200"
+            >
+                {match children {
+                    Some(children) => children().into_view(),
+                    None if show_total => {
+                        view! {
+                            <text
+                                font-size="30px"
+                                text-anchor="middle"
+                                dominant-baseline="central"
+                            >
+                                {move || format.format_localized(sum.get(), locale)}
+                            </text>
+                        }
+                            .into_view()
+                    }
+                    None => ().into_view(),
+                }}
+
+            </svg>
+            {tooltip
+                .map(|options| {
+                    view! { <Tooltip data=hovered pointer=tooltip_position size=size options=options/> }
+                })}
+            {accessibility
+                .data_table
+                .then(|| {
+                    view! {
+                        <DataTable
+                            caption=label
+                            headers=["Segment", "", "Value"]
+                            rows=segment_data
+                        />
+                    }
+                })}
 
         </svg>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arc_paths() {
+        let segment = PieSegment {
+            index: 0,
+            from: (99.0, 0.0),
+            to: (0.0, 99.0),
+            value: 1.0,
+            label: "".to_string(),
+            value_label: "".to_string(),
+        };
+        assert_eq!(segment.get_arc_path(0.0), "M0 0 99 0 A100 100 0 0 1 0 99Z");
+        assert_eq!(
+            segment.get_arc_path(0.5),
+            "M99 0 A100 100 0 0 1 0 99 L0 49.5 A50 50 0 0 0 49.5 0Z"
+        );
+    }
+}
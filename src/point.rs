@@ -38,3 +38,31 @@ impl<T> IntoIterator for Series<T> {
         self.0.into_iter()
     }
 }
+
+/// A named collection of values, e.g. one line in a multi-series chart
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedSeries<T> {
+    pub name: String,
+    pub values: Vec<T>,
+}
+
+impl<T> NamedSeries<T> {
+    pub fn new(name: impl Into<String>, values: Vec<T>) -> Self {
+        NamedSeries {
+            name: name.into(),
+            values,
+        }
+    }
+}
+
+impl<T> From<(String, Vec<T>)> for NamedSeries<T> {
+    fn from(series: (String, Vec<T>)) -> NamedSeries<T> {
+        NamedSeries::new(series.0, series.1)
+    }
+}
+
+impl<T> From<(&str, Vec<T>)> for NamedSeries<T> {
+    fn from(series: (&str, Vec<T>)) -> NamedSeries<T> {
+        NamedSeries::new(series.0, series.1)
+    }
+}
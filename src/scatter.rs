@@ -0,0 +1,413 @@
+use std::rc::Rc;
+
+use crate::{
+    accessibility::{count, AccessibilityOptions, DataTable},
+    axis::{XAxis, YAxis},
+    format::{Locale, Plain, TickFormat},
+    grid::{Grid, GridOptions},
+    layout::{use_chart_size, LayoutOptions, PlotArea},
+    legend::{Legend, LegendOptions},
+    point::NamedSeries,
+    title::{TitleOptions, Titles},
+    tooltip::{show_on_hover, use_pointer, Tooltip, TooltipData, TooltipOptions},
+    utils::{self, Domain, Scale},
+    ChartColor, Palette, CATPPUCCIN_COLORS,
+};
+use leptos::{svg::*, *};
+use leptos_use::*;
+use num_traits::ToPrimitive;
+
+/// Shape of the markers drawn for each point
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MarkerShape {
+    #[default]
+    Circle,
+    Square,
+    Diamond,
+    Triangle,
+    Cross,
+}
+
+impl MarkerShape {
+    /// Path of the marker centered on the origin, `size` is its width in px
+    pub fn path(&self, size: f64) -> String {
+        let r = size / 2.0;
+        match self {
+            MarkerShape::Circle => {
+                format!("M{} 0 A{r} {r} 0 1 0 {r} 0 A{r} {r} 0 1 0 {} 0Z", -r, -r)
+            }
+            MarkerShape::Square => format!("M{} {} H{r} V{r} H{}Z", -r, -r, -r),
+            MarkerShape::Diamond => format!("M0 {} L{r} 0 L0 {r} L{} 0Z", -r, -r),
+            MarkerShape::Triangle => format!("M0 {} L{r} {r} L{} {r}Z", -r, -r),
+            MarkerShape::Cross => format!("M{} {} L{r} {r} M{} {r} L{r} {}", -r, -r, -r, -r),
+        }
+    }
+}
+
+pub struct ScatterChartOptions {
+    pub max_ticks: u8,
+    pub color: Box<dyn ChartColor>,
+    /// shows a legend with the series names if set
+    pub legend: Option<LegendOptions>,
+    pub marker: MarkerShape,
+    /// width of the markers in px
+    pub marker_size: f64,
+    /// colors every point by its index within its series instead of coloring by series
+    pub color_per_point: bool,
+    /// range of the y axis, points outside of it are hidden
+    pub domain: Domain,
+    /// range of the x axis, points outside of it are hidden
+    pub x_domain: Domain,
+    /// draws gridlines behind the data if set
+    pub grid: Option<GridOptions>,
+    /// adds unlabeled ticks between the ticks of linear axes, logarithmic axes always
+    /// have them
+    pub minor_ticks: bool,
+    /// labels of the y axis and the y values of hovered points
+    pub format: Box<dyn TickFormat>,
+    /// labels of the x axis and the x values of hovered points
+    pub x_format: Box<dyn TickFormat>,
+    pub locale: Locale,
+    pub titles: TitleOptions,
+    /// margins around the plot and sizes of the axes
+    pub layout: LayoutOptions,
+    /// shows details of hovered points if set
+    pub tooltip: Option<TooltipOptions>,
+    pub accessibility: AccessibilityOptions,
+}
+
+impl Default for ScatterChartOptions {
+    fn default() -> Self {
+        Self {
+            max_ticks: 5u8,
+            color: Box::new(Palette(CATPPUCCIN_COLORS.clone())),
+            legend: None,
+            marker: MarkerShape::default(),
+            marker_size: 8.0,
+            color_per_point: false,
+            domain: Domain::default(),
+            x_domain: Domain::default(),
+            grid: None,
+            minor_ticks: false,
+            format: Box::new(Plain),
+            x_format: Box::new(Plain),
+            locale: Locale::default(),
+            titles: TitleOptions::default(),
+            layout: LayoutOptions::default(),
+            tooltip: Some(TooltipOptions::default()),
+            accessibility: AccessibilityOptions::default(),
+        }
+    }
+}
+
+/// Responsive scatter plot of one or more series of unconnected points
+///
+/// Example:
+/// ```rust
+/// use leptos_charts::*;
+/// use leptos::*;
+///
+/// let data = vec![(1.0, 2.0), (1.5, 3.5), (2.0, 1.0), (4.0, 3.0)];
+/// let options = Box::new(ScatterChartOptions {
+///     marker: MarkerShape::Diamond,
+///     ..Default::default()
+/// });
+/// # #[cfg(hydrate)]
+/// # {
+/// view!{
+/// <ScatterChart
+///     values=data.into()
+///     options=options
+///     attr:width="300"
+///     attr:height="200"
+/// />
+/// }
+/// # }
+/// # ;
+/// ```
+#[allow(clippy::boxed_local)]
+#[component]
+pub fn ScatterChart<T, U>(
+    /// points of a single series, for multiple series use `series`
+    #[prop(optional)]
+    values: MaybeSignal<Vec<(T, U)>>,
+    /// multiple named series, drawn after the series in `values`
+    #[prop(optional)]
+    series: MaybeSignal<Vec<NamedSeries<(T, U)>>>,
+    options: Box<ScatterChartOptions>,
+    /// name of the series in `values`, shown in the legend. Defaults to "Series 1"
+    #[prop(optional, into)]
+    label: Option<String>,
+    #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
+) -> impl IntoView
+where
+    T: ToPrimitive + Clone + PartialOrd + 'static,
+    U: ToPrimitive + Clone + PartialOrd + 'static,
+{
+    let ScatterChartOptions {
+        max_ticks,
+        color,
+        legend,
+        marker,
+        marker_size,
+        color_per_point,
+        domain,
+        x_domain,
+        grid,
+        minor_ticks: show_minor_ticks,
+        format,
+        x_format,
+        locale,
+        titles,
+        layout,
+        tooltip,
+        accessibility,
+    } = *options;
+    let color: Rc<dyn ChartColor> = color.into();
+    let format: Rc<dyn TickFormat> = format.into();
+    let x_format: Rc<dyn TickFormat> = x_format.into();
+    let tooltip = tooltip.map(Rc::new);
+    let hovered = create_rw_signal(None::<TooltipData>);
+    let series =
+        create_memo(move |_| utils::get_xy_series(values.get(), label.clone(), series.get()));
+    let num_series = create_memo(move |_| series.with(Vec::len));
+    let labels = create_memo(move |_| {
+        series.with(|s| s.iter().map(|s| s.name.clone()).collect::<Vec<String>>())
+    });
+    // what the tooltip shows and screen readers read for every point, series by series
+    let point_data = create_memo({
+        let color = color.clone();
+        let format = format.clone();
+        let x_format = x_format.clone();
+        move |_| {
+            series.with(|series| {
+                series
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, s)| {
+                        let num_points = s.values.len();
+                        s.values
+                            .iter()
+                            .enumerate()
+                            .map(move |(j, point)| (i, &s.name, j, num_points, point))
+                    })
+                    .map(|(i, name, j, num_points, (x, y))| TooltipData {
+                        name: name.clone(),
+                        x: Some(x_format.format_localized(*x, locale)),
+                        value: format.format_localized(*y, locale),
+                        color: String::from(if color_per_point {
+                            color.color_for_index(j, num_points)
+                        } else {
+                            color.color_for_index(i, num_series.get())
+                        }),
+                    })
+                    .collect::<Vec<TooltipData>>()
+            })
+        }
+    });
+    let label = accessibility.label_for(&titles, "Scatter chart");
+    let description = accessibility.description_or(move || {
+        format!(
+            "{} of {}: {}",
+            count(num_series.get(), "series", "series"),
+            count(point_data.with(Vec::len), "point", "points"),
+            labels.get().join(", ")
+        )
+    });
+    let svg_ref = create_node_ref::<Svg>();
+    let size = use_chart_size(svg_ref);
+    let pointer = use_pointer(svg_ref);
+    let title_region = create_memo({
+        let titles = titles.clone();
+        move |_| titles.remaining_area(PlotArea::FULL, size.get())
+    });
+    let axes_region = create_memo(move |_| {
+        let region = title_region.get();
+        legend
+            .map(|legend| labels.with(|l| legend.remaining_area(l, region, size.get())))
+            .unwrap_or(region)
+    });
+    let tick_config = create_memo(move |_| {
+        let ys = series.with(|s| {
+            s.iter()
+                .flat_map(|s| s.values.iter().map(|(_, y)| *y))
+                .collect::<Vec<f64>>()
+        });
+        utils::scale_ticks(Scale::Linear, &domain, &ys, max_ticks)
+    });
+    let ticks = create_memo({
+        let format = format.clone();
+        move |_| tick_config.with(|t| utils::get_ticks(t, format.as_ref(), locale))
+    });
+    let x_tick_config = create_memo(move |_| {
+        let xs = series.with(|s| {
+            s.iter()
+                .flat_map(|s| s.values.iter().map(|(x, _)| *x))
+                .collect::<Vec<f64>>()
+        });
+        utils::scale_ticks(Scale::Linear, &x_domain, &xs, max_ticks)
+    });
+    let x_ticks = create_memo({
+        let x_format = x_format.clone();
+        move |_| x_tick_config.with(|t| utils::get_x_ticks(t, x_format.as_ref(), locale))
+    });
+    let plot_area = create_memo({
+        let titles = titles.clone();
+        move |_| {
+            let region = titles.axes_area(axes_region.get(), size.get());
+            ticks.with(|y| x_ticks.with(|x| layout.plot_area(region, size.get(), y, x, &[])))
+        }
+    });
+    let marker_path = marker.path(marker_size);
+
+    view! {
+        <svg role="figure" {..attrs} _ref=svg_ref>
+            <title>{label.clone()}</title>
+            <desc>{description}</desc>
+            {grid
+                .map(|grid| {
+                    view! {
+                        <Grid
+                            horizontal=Signal::derive(move || {
+                                ticks.with(|t| t.iter().map(|t| t.0).collect())
+                            })
+
+                            vertical=Signal::derive(move || {
+                                x_ticks.with(|t| t.iter().map(|t| t.0).collect())
+                            })
+
+                            horizontal_minor=Signal::derive(move || {
+                                tick_config.with(|t| utils::get_minor_ticks(t, true))
+                            })
+
+                            vertical_minor=Signal::derive(move || {
+                                x_tick_config.with(|t| utils::get_x_minor_ticks(t, true))
+                            })
+
+                            options=grid
+                            area=plot_area
+                        />
+                    }
+                })}
+            <YAxis
+                ticks=ticks
+                minor_ticks=Signal::derive(move || {
+                    tick_config.with(|t| utils::get_minor_ticks(t, show_minor_ticks))
+                })
+
+                area=plot_area
+                size=size
+                layout=layout
+            />
+            <XAxis
+                ticks=x_ticks
+                minor_ticks=Signal::derive(move || {
+                    x_tick_config.with(|t| utils::get_x_minor_ticks(t, show_minor_ticks))
+                })
+
+                area=plot_area
+                size=size
+                layout=layout
+            />
+            <Titles
+                options=titles
+                region=title_region
+                axes_region=axes_region
+                plot=plot_area
+                size=size
+            />
+            {legend
+                .map(|legend| {
+                    view! {
+                        <Legend
+                            labels=labels
+                            color=color.clone()
+                            options=legend
+                            region=move || legend.region(title_region.get(), plot_area.get())
+                            size=size
+                        />
+                    }
+                })}
+
+            {move || {
+                series
+                    .get()
+                    .into_iter()
+                    .flat_map(|s| s.values)
+                    .zip(point_data.get())
+                    .map(|((x, y), data)| {
+                        let el = create_node_ref::<Path>();
+                        let is_hovered = use_element_hover(el);
+                        let color = data.color.clone();
+                        let x_pos = move || x_tick_config.with(|t| t.position(x));
+                        let y_pos = move || tick_config.with(|t| t.position(y));
+                        let pos_x = move || format!("{}%", plot_area.get().x_at(x_pos()));
+                        let pos_y = move || format!("{}%", plot_area.get().y_at(100.0 - y_pos()));
+                        let inside = move || {
+                            (0.0..=100.0).contains(&x_pos()) && (0.0..=100.0).contains(&y_pos())
+                        };
+                        let aria_label = data.aria_label();
+                        show_on_hover(is_hovered, data, hovered);
+                        view! {
+                            <svg
+                                x=pos_x
+                                y=pos_y
+                                overflow="visible"
+                                display=move || if inside() { "inline" } else { "none" }
+                            >
+                                <path
+                                    node_ref=el
+                                    d=marker_path.clone()
+                                    fill=color.clone()
+                                    fill-opacity=move || {
+                                        if is_hovered.get() { "0.8" } else { "0.6" }
+                                    }
+
+                                    stroke=color
+                                    stroke-width=move || {
+                                        if is_hovered.get() { "3px" } else { "1px" }
+                                    }
+
+                                    vector-effect="non-scaling-stroke"
+                                    role="img"
+                                    aria-label=aria_label
+                                ></path>
+                            </svg>
+                        }
+                    })
+                    .collect_view()
+            }}
+            {tooltip
+                .map(|options| {
+                    view! { <Tooltip data=hovered pointer=pointer size=size options=options/> }
+                })}
+            {accessibility
+                .data_table
+                .then(|| {
+                    view! {
+                        <DataTable
+                            caption=label
+                            headers=["Series", "X", "Y"]
+                            rows=point_data
+                        />
+                    }
+                })}
+
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marker_paths() {
+        assert_eq!(MarkerShape::Square.path(4.0), "M-2 -2 H2 V2 H-2Z");
+        assert_eq!(MarkerShape::Diamond.path(4.0), "M0 -2 L2 0 L0 2 L-2 0Z");
+        assert_eq!(
+            MarkerShape::Circle.path(4.0),
+            "M-2 0 A2 2 0 1 0 2 0 A2 2 0 1 0 -2 0Z"
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use num_traits::ToPrimitive;
+
+use crate::utils::{Scale, TickSpacing};
+
+/// Data is clamped to this many seconds around the epoch (about 30000 years)
+const MAX_SECONDS: f64 = 1e12;
+
+/// Ticks can lie an interval beyond the clamped data, calendar computations on times up to
+/// this far from the epoch still can't overflow
+const MAX_TICK_SECONDS: f64 = 1e14;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Point in time in seconds since the Unix epoch, for values on a [`Scale::Time`] axis.
+///
+/// Any other `ToPrimitive` value on a time axis is interpreted as seconds since the epoch
+/// as well, this is just a convenient way to get there from milliseconds or `SystemTime`.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Timestamp(pub f64);
+
+impl Timestamp {
+    pub fn from_secs(secs: i64) -> Self {
+        Self(secs as f64)
+    }
+
+    /// e.g. from javascript's `Date.now()`
+    pub fn from_millis(millis: i64) -> Self {
+        Self(millis as f64 / 1000.0)
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => Self(after.as_secs_f64()),
+            Err(before) => Self(-before.duration().as_secs_f64()),
+        }
+    }
+}
+
+impl ToPrimitive for Timestamp {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    /// Length in seconds, months and years are approximated
+    fn seconds(&self) -> f64 {
+        match self {
+            TimeUnit::Second => 1.0,
+            TimeUnit::Minute => 60.0,
+            TimeUnit::Hour => 3600.0,
+            TimeUnit::Day => 86400.0,
+            TimeUnit::Month => 86400.0 * 30.44,
+            TimeUnit::Year => 86400.0 * 365.25,
+        }
+    }
+}
+
+/// Distance between two ticks on a time axis
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeInterval {
+    pub unit: TimeUnit,
+    pub count: u32,
+}
+
+/// Intervals that ticks are placed at, from small to large
+const INTERVALS: [(TimeUnit, u32); 26] = [
+    (TimeUnit::Second, 1),
+    (TimeUnit::Second, 5),
+    (TimeUnit::Second, 15),
+    (TimeUnit::Second, 30),
+    (TimeUnit::Minute, 1),
+    (TimeUnit::Minute, 5),
+    (TimeUnit::Minute, 15),
+    (TimeUnit::Minute, 30),
+    (TimeUnit::Hour, 1),
+    (TimeUnit::Hour, 3),
+    (TimeUnit::Hour, 6),
+    (TimeUnit::Hour, 12),
+    (TimeUnit::Day, 1),
+    (TimeUnit::Day, 2),
+    (TimeUnit::Day, 7),
+    (TimeUnit::Month, 1),
+    (TimeUnit::Month, 3),
+    (TimeUnit::Month, 6),
+    (TimeUnit::Year, 1),
+    (TimeUnit::Year, 2),
+    (TimeUnit::Year, 5),
+    (TimeUnit::Year, 10),
+    (TimeUnit::Year, 20),
+    (TimeUnit::Year, 50),
+    (TimeUnit::Year, 100),
+    (TimeUnit::Year, 1000),
+];
+
+/// Days since the epoch of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // see http://howardhinnant.github.io/date_algorithms.html
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// (year, month, day) of a number of days since the epoch
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+/// Months since year 0 of a timestamp in seconds
+fn month_index(time: i64) -> i64 {
+    let (year, month, _) = civil_from_days(time.div_euclid(86400));
+    year * 12 + month - 1
+}
+
+/// Timestamp in seconds of the start of a month since year 0
+fn from_month_index(index: i64) -> i64 {
+    days_from_civil(index.div_euclid(12), index.rem_euclid(12) + 1, 1) * 86400
+}
+
+impl TimeInterval {
+    /// Length in months for calendar based intervals
+    fn months(&self) -> Option<i64> {
+        match self.unit {
+            TimeUnit::Month => Some(self.count as i64),
+            TimeUnit::Year => Some(12 * self.count as i64),
+            _ => None,
+        }
+    }
+
+    /// Fixed length intervals are aligned to the epoch, except for weeks which start on
+    /// Monday (the epoch was a Thursday)
+    fn offset(&self) -> i64 {
+        if self.unit == TimeUnit::Day && self.count == 7 {
+            3 * 86400
+        } else {
+            0
+        }
+    }
+
+    fn approx_seconds(&self) -> f64 {
+        self.unit.seconds() * self.count as f64
+    }
+
+    /// Latest tick at or before `time`
+    fn floor(&self, time: i64) -> i64 {
+        match self.months() {
+            Some(months) => from_month_index(month_index(time).div_euclid(months) * months),
+            None => {
+                let length = self.approx_seconds() as i64;
+                (time + self.offset()).div_euclid(length) * length - self.offset()
+            }
+        }
+    }
+
+    /// Earliest tick at or after `time`
+    fn ceil(&self, time: i64) -> i64 {
+        let floor = self.floor(time);
+        if floor == time {
+            floor
+        } else {
+            self.add(floor, 1)
+        }
+    }
+
+    /// Moves the tick at `time` by `n` intervals
+    fn add(&self, time: i64, n: i64) -> i64 {
+        match self.months() {
+            Some(months) => from_month_index(month_index(time) + n * months),
+            None => time + n * self.approx_seconds() as i64,
+        }
+    }
+
+    /// Label of the tick at `time`, with a precision that fits the interval
+    pub fn format(&self, time: f64) -> String {
+        let time = time.clamp(-MAX_TICK_SECONDS, MAX_TICK_SECONDS).floor() as i64;
+        let (year, month, day) = civil_from_days(time.div_euclid(86400));
+        let seconds = time.rem_euclid(86400);
+        let (hour, minute, second) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        let month = MONTH_NAMES[month as usize - 1];
+        match self.unit {
+            TimeUnit::Second => format!("{:02}:{:02}:{:02}", hour, minute, second),
+            // ticks at midnight show the date instead, to make day changes visible
+            TimeUnit::Minute | TimeUnit::Hour if seconds != 0 => {
+                format!("{:02}:{:02}", hour, minute)
+            }
+            TimeUnit::Minute | TimeUnit::Hour | TimeUnit::Day => format!("{} {}", month, day),
+            TimeUnit::Month => format!("{} {}", month, year),
+            TimeUnit::Year => format!("{}", year),
+        }
+    }
+
+    /// Full date of a single value, e.g. a hovered point, down to the precision of the
+    /// interval
+    pub fn format_value(&self, time: f64) -> String {
+        let time = time.clamp(-MAX_TICK_SECONDS, MAX_TICK_SECONDS).floor() as i64;
+        let (year, month, day) = civil_from_days(time.div_euclid(86400));
+        let seconds = time.rem_euclid(86400);
+        let (hour, minute, second) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        let date = format!("{} {}, {}", MONTH_NAMES[month as usize - 1], day, year);
+        match self.unit {
+            TimeUnit::Second => format!("{} {:02}:{:02}:{:02}", date, hour, minute, second),
+            TimeUnit::Minute | TimeUnit::Hour => format!("{} {:02}:{:02}", date, hour, minute),
+            TimeUnit::Day | TimeUnit::Month | TimeUnit::Year => date,
+        }
+    }
+}
+
+/// Ticks on calendar aligned intervals between `min` and `max` (in seconds since the epoch),
+/// at most `max_ticks` of them if possible
+pub fn time_ticks(min: f64, max: f64, max_ticks: u8) -> TickSpacing {
+    let (min, max) = match (min.is_finite(), max.is_finite()) {
+        (true, true) => (min.min(max), min.max(max)),
+        (true, false) => (min, min),
+        (false, true) => (max, max),
+        (false, false) => (0.0, 0.0),
+    };
+    let (min, max) = (
+        min.clamp(-MAX_SECONDS, MAX_SECONDS).floor() as i64,
+        max.clamp(-MAX_SECONDS, MAX_SECONDS).ceil() as i64,
+    );
+    let max_ticks = max_ticks.max(2) as i64;
+    let interval = INTERVALS
+        .iter()
+        .map(|(unit, count)| TimeInterval {
+            unit: *unit,
+            count: *count,
+        })
+        .find(|interval| {
+            // the approximation keeps small intervals over long ranges from being counted
+            (max - min) as f64 / interval.approx_seconds() <= max_ticks as f64 && {
+                let (first, last) = (interval.floor(min), interval.ceil(max));
+                (0..max_ticks).any(|i| interval.add(first, i) >= last)
+            }
+        })
+        .unwrap_or(TimeInterval {
+            unit: TimeUnit::Year,
+            count: 1000,
+        });
+    let min_point = interval.floor(min);
+    let max_point = interval.ceil(max).max(interval.add(min_point, 1));
+    let num_ticks = (0..)
+        .take_while(|i| interval.add(min_point, *i) <= max_point)
+        .count();
+    TickSpacing {
+        min_point: min_point as f64,
+        max_point: max_point as f64,
+        spacing: interval.approx_seconds(),
+        num_ticks: num_ticks.min(u8::MAX as usize) as u8,
+        scale: Scale::Time,
+        interval: Some(interval),
+    }
+}
+
+/// Ticks of a time axis, starting with the first tick at or after `min`
+pub(crate) fn time_values(min: f64, interval: TimeInterval, num_ticks: u8) -> Vec<f64> {
+    let first = interval.ceil(min.clamp(-MAX_TICK_SECONDS, MAX_TICK_SECONDS).ceil() as i64);
+    (0..num_ticks as i64)
+        .map(|i| interval.add(first, i) as f64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calendar() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 2, 29), 19782);
+        assert_eq!(civil_from_days(19782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(
+            from_month_index(month_index(19782 * 86400 + 5)),
+            19754 * 86400
+        );
+    }
+
+    #[test]
+    fn intervals() {
+        // 2024-01-01 00:00:00 UTC
+        let start = 1704067200.0;
+        let ticks = time_ticks(start + 20.0, start + 200.0, 5);
+        assert_eq!(
+            ticks.interval,
+            Some(TimeInterval {
+                unit: TimeUnit::Minute,
+                count: 1
+            })
+        );
+        assert_eq!(ticks.min_point, start);
+        assert_eq!(ticks.max_point, start + 240.0);
+        assert_eq!(ticks.num_ticks, 5);
+
+        let ticks = time_ticks(start + 86400.0 * 20.0, start + 86400.0 * 100.0, 5);
+        assert_eq!(
+            ticks.interval,
+            Some(TimeInterval {
+                unit: TimeUnit::Month,
+                count: 1
+            })
+        );
+        assert_eq!(
+            ticks.values(),
+            vec![
+                start + 86400.0 * 0.0,
+                start + 86400.0 * 31.0,
+                start + 86400.0 * 60.0,
+                start + 86400.0 * 91.0,
+                start + 86400.0 * 121.0,
+            ]
+        );
+
+        let ticks = time_ticks(0.0, 86400.0 * 365.0 * 30.0, 5);
+        assert_eq!(ticks.interval.map(|i| i.count), Some(10));
+        assert_eq!(ticks.num_ticks, 4);
+    }
+
+    #[test]
+    fn labels() {
+        let start = 1704067200.0;
+        let hours = TimeInterval {
+            unit: TimeUnit::Hour,
+            count: 3,
+        };
+        assert_eq!(hours.format(start + 3.0 * 3600.0), "03:00");
+        assert_eq!(hours.format(start), "Jan 1");
+        let months = TimeInterval {
+            unit: TimeUnit::Month,
+            count: 1,
+        };
+        assert_eq!(months.format(start + 86400.0 * 31.0), "Feb 2024");
+        assert_eq!(hours.format_value(start + 5400.0), "Jan 1, 2024 01:30");
+        assert_eq!(months.format_value(start + 5400.0), "Jan 1, 2024");
+    }
+}
@@ -0,0 +1,201 @@
+use crate::layout::PlotArea;
+use leptos::*;
+
+/// Title and subtitle above a chart, and titles of its axes.
+///
+/// Every title that is set takes space away from the plot, so that it doesn't overlap the
+/// axis labels.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TitleOptions {
+    pub title: Option<String>,
+    /// smaller line below the title
+    pub subtitle: Option<String>,
+    /// shown below the labels of the horizontal axis
+    pub x_title: Option<String>,
+    /// shown rotated left of the labels of the vertical axis
+    pub y_title: Option<String>,
+    /// font size of the title in px, the subtitle and axis titles are drawn at 75% of it
+    pub font_size: f64,
+}
+
+impl Default for TitleOptions {
+    fn default() -> Self {
+        Self {
+            title: None,
+            subtitle: None,
+            x_title: None,
+            y_title: None,
+            font_size: 16.0,
+        }
+    }
+}
+
+impl TitleOptions {
+    fn small_font_size(&self) -> f64 {
+        self.font_size * 0.75
+    }
+
+    /// Height in px of the title and subtitle
+    fn header_height(&self) -> f64 {
+        let title = self.title.as_ref().map_or(0.0, |_| self.font_size * 1.5);
+        let subtitle = self
+            .subtitle
+            .as_ref()
+            .map_or(0.0, |_| self.small_font_size() * 1.5);
+        title + subtitle
+    }
+
+    /// Thickness in px of the strip an axis title is drawn in
+    fn axis_title_size(&self, title: &Option<String>) -> f64 {
+        title.as_ref().map_or(0.0, |_| self.small_font_size() * 1.5)
+    }
+
+    /// What remains of `region` below the title and subtitle
+    pub fn remaining_area(&self, region: PlotArea, size: (f64, f64)) -> PlotArea {
+        region.inset(100.0 * self.header_height() / size.1, 0.0, 0.0, 0.0)
+    }
+
+    /// What remains of `region` once the axis titles are placed along its left and bottom
+    /// edges. The axes and their labels go into the result.
+    pub fn axes_area(&self, region: PlotArea, size: (f64, f64)) -> PlotArea {
+        region.inset(
+            0.0,
+            0.0,
+            100.0 * self.axis_title_size(&self.x_title) / size.1,
+            100.0 * self.axis_title_size(&self.y_title) / size.0,
+        )
+    }
+}
+
+/// Draws the titles of [`TitleOptions`].
+///
+/// The title and subtitle are centered at the top of `region`, the axis titles along the
+/// left and bottom edges of `axes_region` (both as passed to
+/// [`TitleOptions::remaining_area`] and [`TitleOptions::axes_area`]), centered on `plot`.
+/// `size` is the size of the chart in px, needed to rotate the title of the vertical axis.
+#[component]
+pub fn Titles(
+    options: TitleOptions,
+    #[prop(into)] region: Signal<PlotArea>,
+    #[prop(into)] axes_region: Signal<PlotArea>,
+    #[prop(into)] plot: Signal<PlotArea>,
+    #[prop(into)] size: Signal<(f64, f64)>,
+) -> impl IntoView {
+    let font_size = options.font_size;
+    let small_font_size = options.small_font_size();
+    let title_height = options.title.as_ref().map_or(0.0, |_| font_size * 1.5);
+    let y_title_size = options.axis_title_size(&options.y_title);
+    let x_title_size = options.axis_title_size(&options.x_title);
+    // the title's center in px, left of the plot
+    let y_title_center = move || {
+        let size = size.get();
+        (
+            axes_region.get().x * size.0 / 100.0 + y_title_size / 2.0,
+            plot.get().y_at(50.0) * size.1 / 100.0,
+        )
+    };
+
+    view! {
+        {options
+            .title
+            .map(|title| {
+                view! {
+                    <text
+                        x=move || format!("{}%", region.get().x_at(50.0))
+                        y=move || format!("{}%", region.get().y)
+                        dy=font_size * 0.75
+                        font-size=format!("{}px", font_size)
+                        font-weight="bold"
+                        text-anchor="middle"
+                        dominant-baseline="central"
+                    >
+                        {title}
+                    </text>
+                }
+            })}
+        {options
+            .subtitle
+            .map(|subtitle| {
+                view! {
+                    <text
+                        x=move || format!("{}%", region.get().x_at(50.0))
+                        y=move || format!("{}%", region.get().y)
+                        dy=title_height + small_font_size * 0.75
+                        font-size=format!("{}px", small_font_size)
+                        text-anchor="middle"
+                        dominant-baseline="central"
+                    >
+                        {subtitle}
+                    </text>
+                }
+            })}
+        {options
+            .x_title
+            .map(|x_title| {
+                view! {
+                    <text
+                        x=move || format!("{}%", plot.get().x_at(50.0))
+                        y=move || format!("{}%", axes_region.get().bottom())
+                        dy=x_title_size / 2.0
+                        font-size=format!("{}px", small_font_size)
+                        text-anchor="middle"
+                        dominant-baseline="central"
+                    >
+                        {x_title}
+                    </text>
+                }
+            })}
+        {options
+            .y_title
+            .map(|y_title| {
+                view! {
+                    <text
+                        x=move || y_title_center().0
+                        y=move || y_title_center().1
+                        transform=move || {
+                            let (x, y) = y_title_center();
+                            format!("rotate(-90 {} {})", x, y)
+                        }
+
+                        font-size=format!("{}px", small_font_size)
+                        text-anchor="middle"
+                        dominant-baseline="central"
+                    >
+                        {y_title}
+                    </text>
+                }
+            })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserves_space() {
+        let options = TitleOptions {
+            title: Some("Sales".to_string()),
+            subtitle: Some("per region".to_string()),
+            x_title: Some("month".to_string()),
+            y_title: Some("units".to_string()),
+            font_size: 20.0,
+        };
+        // 30px title and 22.5px subtitle of 200px
+        let region = options.remaining_area(PlotArea::FULL, (300.0, 200.0));
+        assert_eq!(region, PlotArea::new(0.0, 26.25, 100.0, 73.75));
+        // 22.5px for each axis title
+        let axes = options.axes_area(PlotArea::FULL, (225.0, 450.0));
+        assert_eq!(axes, PlotArea::new(10.0, 0.0, 90.0, 95.0));
+
+        let options = TitleOptions::default();
+        assert_eq!(
+            options.remaining_area(PlotArea::FULL, (300.0, 200.0)),
+            PlotArea::FULL
+        );
+        assert_eq!(
+            options.axes_area(PlotArea::FULL, (300.0, 200.0)),
+            PlotArea::FULL
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use std::rc::Rc;
+
+use leptos::{html::ElementDescriptor, *};
+use leptos_use::{use_mouse_in_element, UseMouseInElementReturn};
+
+/// Distance in px between the pointer and the tooltip
+const POINTER_OFFSET: f64 = 12.0;
+
+/// A hovered value, as shown by a [`Tooltip`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TooltipData {
+    /// name of the series, or label of the bar or segment
+    pub name: String,
+    /// formatted x value or category, if it isn't already the name
+    pub x: Option<String>,
+    /// formatted value
+    pub value: String,
+    /// color of the series, bar or segment
+    pub color: String,
+}
+
+pub struct TooltipOptions {
+    /// font size of the default content in px
+    pub font_size: f64,
+    /// replaces the default content (a color swatch, the name and the values) with any view,
+    /// rendered as html
+    pub content: Option<Box<dyn Fn(TooltipData) -> View>>,
+}
+
+impl Default for TooltipOptions {
+    fn default() -> Self {
+        Self {
+            font_size: 12.0,
+            content: None,
+        }
+    }
+}
+
+/// Tracks the pointer position in px, relative to the top left corner of `target`
+pub fn use_pointer<El>(target: NodeRef<El>) -> Signal<(f64, f64)>
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    let UseMouseInElementReturn {
+        element_x,
+        element_y,
+        ..
+    } = use_mouse_in_element(target);
+    Signal::derive(move || (element_x.get(), element_y.get()))
+}
+
+/// Shows `data` in `tooltip` while `hovered` is set
+pub(crate) fn show_on_hover(
+    hovered: Signal<bool>,
+    data: TooltipData,
+    tooltip: RwSignal<Option<TooltipData>>,
+) {
+    create_effect(move |_| {
+        if hovered.get() {
+            tooltip.set(Some(data.clone()));
+        } else if tooltip.with_untracked(|t| t.as_ref() == Some(&data)) {
+            tooltip.set(None);
+        }
+    });
+}
+
+/// CSS placing the tooltip next to `pointer`, on the side with more room so that it stays
+/// within a chart of `size` px as long as it's no larger than half the chart
+fn placement(pointer: (f64, f64), size: (f64, f64)) -> String {
+    let horizontal = if pointer.0 > size.0 / 2.0 {
+        format!("right: {}px", size.0 - pointer.0 + POINTER_OFFSET)
+    } else {
+        format!("left: {}px", pointer.0 + POINTER_OFFSET)
+    };
+    let vertical = if pointer.1 > size.1 / 2.0 {
+        format!("bottom: {}px", size.1 - pointer.1 + POINTER_OFFSET)
+    } else {
+        format!("top: {}px", pointer.1 + POINTER_OFFSET)
+    };
+    format!("{}; {}", horizontal, vertical)
+}
+
+/// Box following the pointer with details about the hovered value, hidden while `data` is
+/// `None`.
+///
+/// `pointer` and `size` are in px relative to the chart, e.g. from [`use_pointer`] and
+/// [`crate::layout::use_chart_size`].
+#[component]
+pub fn Tooltip(
+    #[prop(into)] data: Signal<Option<TooltipData>>,
+    #[prop(into)] pointer: Signal<(f64, f64)>,
+    #[prop(into)] size: Signal<(f64, f64)>,
+    options: Rc<TooltipOptions>,
+) -> impl IntoView {
+    let font_size = options.font_size;
+    let content = move |data: TooltipData| match &options.content {
+        Some(content) => content(data),
+        None => view! {
+            <div style="display: flex; align-items: center; gap: 0.4em">
+                <span style=format!(
+                    "display: inline-block; width: 0.8em; height: 0.8em; background: {}",
+                    data.color,
+                )></span>
+                <b>{data.name}</b>
+            </div>
+            {data.x.map(|x| view! { <div>{x}</div> })}
+            <div>{data.value}</div>
+        }
+        .into_view(),
+    };
+
+    view! {
+        <foreignObject
+            x="0"
+            y="0"
+            width="100%"
+            height="100%"
+            pointer-events="none"
+            aria-hidden="true"
+        >
+            <div style="position: relative; width: 100%; height: 100%">
+                {move || {
+                    data.get()
+                        .map(|data| {
+                            view! {
+                                <div style=move || {
+                                    format!(
+                                        "position: absolute; {}; background: white; border: 1px solid #ccc; border-radius: 3px; padding: 4px 6px; font-size: {}px; white-space: nowrap; box-shadow: 0 1px 3px rgba(0, 0, 0, 0.2)",
+                                        placement(pointer.get(), size.get()),
+                                        font_size,
+                                    )
+                                }>{content(data)}</div>
+                            }
+                        })
+                }}
+
+            </div>
+        </foreignObject>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stays_inside() {
+        assert_eq!(
+            placement((50.0, 40.0), (300.0, 200.0)),
+            "left: 62px; top: 52px"
+        );
+        assert_eq!(
+            placement((250.0, 150.0), (300.0, 200.0)),
+            "right: 62px; bottom: 62px"
+        );
+    }
+}
@@ -1,11 +1,277 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{
+    format::{Locale, TickFormat},
+    point::NamedSeries,
+    time::{self, TimeInterval},
+};
+use leptos::logging::warn;
 use num_traits::ToPrimitive;
 
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Relative tolerance for rounding errors when checking whether a value is a tick
+const TOLERANCE: f64 = 1e-9;
+
+/// Largest magnitude of values on linear axes, larger values are clamped so that tick
+/// computations can't overflow
+const MAX_MAGNITUDE: f64 = 1e300;
+
+/// Largest (and inverse of the smallest) value on logarithmic axes, ticks can be a few powers
+/// beyond the data
+const MAX_LOG_MAGNITUDE: f64 = 1e200;
+
+/// Rounds `value` to the precision of `step`, to remove errors accumulated by adding up steps
+/// (e.g. `0.1 + 0.2`)
+fn round_to_step(value: f64, step: f64) -> f64 {
+    let factor = 10.0f64.powf(1.0 - step.log10().floor());
+    if factor > 1.0 && factor.is_finite() && (value * factor).is_finite() {
+        (value * factor).round() / factor
+    } else {
+        value
+    }
+}
+
+/// Finite `min < max` for ticks of a linear axis.
+///
+/// Non-finite ends are ignored (falling back to `0..1` if neither is finite), values are
+/// clamped to [`MAX_MAGNITUDE`], and ranges too small to put ticks in are widened around
+/// their center by 1 or by 10% of the value, whichever is larger.
+fn sanitize_range(min: f64, max: f64) -> (f64, f64) {
+    let (min, max) = match (min.is_finite(), max.is_finite()) {
+        (true, true) => (min.min(max), min.max(max)),
+        (true, false) => (min, min),
+        (false, true) => (max, max),
+        (false, false) => (0.0, 1.0),
+    };
+    let (min, max) = (
+        min.clamp(-MAX_MAGNITUDE, MAX_MAGNITUDE),
+        max.clamp(-MAX_MAGNITUDE, MAX_MAGNITUDE),
+    );
+    let magnitude = min.abs().max(max.abs());
+    if max - min > magnitude * 1e-12 && max - min > 1e-290 {
+        return (min, max);
+    }
+    let center = min / 2.0 + max / 2.0;
+    let delta = (center.abs() * 0.1).max(1.0);
+    (center - delta, center + delta)
+}
+
+/// Positive `min < max` for ticks of a logarithmic axis, like [`sanitize_range`]
+fn sanitize_log_range(min: f64, max: f64, base: f64) -> (f64, f64) {
+    let valid = |v: f64| v.is_finite() && v > 0.0;
+    let (min, max) = match (valid(min), valid(max)) {
+        (true, true) => (min.min(max), min.max(max)),
+        (true, false) => (min, min),
+        (false, true) => (max, max),
+        (false, false) => (1.0, base),
+    };
+    let (min, max) = (
+        min.clamp(1.0 / MAX_LOG_MAGNITUDE, MAX_LOG_MAGNITUDE),
+        max.clamp(1.0 / MAX_LOG_MAGNITUDE, MAX_LOG_MAGNITUDE),
+    );
+    if max > min {
+        (min, max)
+    } else {
+        (min / base, max * base)
+    }
+}
+
+/// Id that is unique per call, to keep svg element ids of several charts on a page apart
+pub fn unique_id() -> usize {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Mapping of values to positions along an axis
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Scale {
+    #[default]
+    Linear,
+    /// logarithmic scale with ticks on powers of 10, only works for positive values
+    Log10,
+    /// logarithmic scale with ticks on powers of 2, only works for positive values
+    Log2,
+    /// linear scale of seconds since the Unix epoch (see [`crate::Timestamp`]), with ticks
+    /// on calendar intervals in UTC
+    Time,
+}
+
+impl Scale {
+    pub fn is_log(&self) -> bool {
+        self.base().is_some()
+    }
+
+    /// Base of logarithmic scales
+    pub(crate) fn base(&self) -> Option<f64> {
+        match self {
+            Scale::Linear | Scale::Time => None,
+            Scale::Log10 => Some(10.0),
+            Scale::Log2 => Some(2.0),
+        }
+    }
+}
+
+/// Ticks of an axis.
+///
+/// For logarithmic scales, `spacing` is the distance between ticks in powers of the base,
+/// for time scales it's the approximate length of `interval` in seconds.
 #[derive(Clone, Debug, PartialEq)]
 pub struct TickSpacing {
     pub min_point: f64,
     pub max_point: f64,
     pub spacing: f64,
     pub num_ticks: u8,
+    pub scale: Scale,
+    /// calendar interval between the ticks of a time scale
+    pub interval: Option<TimeInterval>,
+}
+
+impl TickSpacing {
+    /// Position of `value` along the axis in percent, from `min_point`.
+    ///
+    /// Values below `min_point` on a logarithmic scale are clamped to it.
+    pub fn position(&self, value: f64) -> f64 {
+        match self.scale.base() {
+            None => 100.0 * (value - self.min_point) / (self.max_point - self.min_point),
+            Some(base) => {
+                let value = value.max(self.min_point);
+                100.0 * (value.log(base) - self.min_point.log(base))
+                    / (self.max_point.log(base) - self.min_point.log(base))
+            }
+        }
+    }
+
+    /// Position of a point of a line in percent, like [`TickSpacing::position`] but values
+    /// outside of the axis stay outside of it, so that they're clipped instead of drawn on
+    /// its ends. Area bottoms at `-inf` and values <= 0 on logarithmic scales are drawn at
+    /// `min_point`.
+    pub fn point_position(&self, value: f64) -> f64 {
+        match self.scale.base() {
+            Some(base) if value > 0.0 => {
+                100.0 * (value.log(base) - self.min_point.log(base))
+                    / (self.max_point.log(base) - self.min_point.log(base))
+            }
+            _ if value == f64::NEG_INFINITY => 0.0,
+            _ => self.position(value),
+        }
+    }
+
+    /// Value at `position` along the axis in percent, the inverse of [`TickSpacing::position`]
+    pub fn value_at(&self, position: f64) -> f64 {
+        match self.scale.base() {
+            None => self.min_point + (self.max_point - self.min_point) * position / 100.0,
+            Some(base) => {
+                let (min, max) = (self.min_point.log(base), self.max_point.log(base));
+                base.powf(min + (max - min) * position / 100.0)
+            }
+        }
+    }
+
+    /// Exponent of the first major tick on a logarithmic scale
+    fn first_exponent(&self, base: f64) -> f64 {
+        (self.min_point.log(base) - TOLERANCE).ceil()
+    }
+
+    /// Values of the major ticks, starting with the first tick at or above `min_point`
+    pub fn values(&self) -> Vec<f64> {
+        if let Some(interval) = self.interval {
+            return time::time_values(self.min_point, interval, self.num_ticks);
+        }
+        let first = match self.scale.base() {
+            None => (self.min_point / self.spacing - TOLERANCE).ceil() * self.spacing,
+            Some(base) => self.first_exponent(base),
+        };
+        (0..self.num_ticks)
+            .map(|i| match self.scale.base() {
+                None => round_to_step(first + i as f64 * self.spacing, self.spacing),
+                Some(base) => base.powf(first + i as f64 * self.spacing),
+            })
+            .collect()
+    }
+
+    /// Same ticks, but with the axis ending at `min` and `max` instead
+    fn within(self, min: f64, max: f64) -> TickSpacing {
+        let ticks = TickSpacing {
+            min_point: min,
+            max_point: max,
+            num_ticks: u8::MAX,
+            ..self
+        };
+        let num_ticks = ticks
+            .values()
+            .into_iter()
+            .take_while(|v| ticks.position(*v) <= 100.0 + TOLERANCE)
+            .count();
+        TickSpacing {
+            num_ticks: num_ticks as u8,
+            ..ticks
+        }
+    }
+
+    /// Label of the tick at `value`, ticks of time scales always show dates and times
+    pub fn label(&self, value: f64, format: &dyn TickFormat, locale: Locale) -> String {
+        match self.interval {
+            Some(interval) => interval.format(value),
+            None => format.format_localized(value, locale),
+        }
+    }
+
+    /// Label of a single value on the axis, like [`TickSpacing::label`] but with full dates
+    /// on time scales
+    pub fn value_label(&self, value: f64, format: &dyn TickFormat, locale: Locale) -> String {
+        match self.interval {
+            Some(interval) => interval.format_value(value),
+            None => format.format_localized(value, locale),
+        }
+    }
+
+    /// Values of the minor ticks between the major ticks.
+    ///
+    /// Linear scales divide every step between major ticks into 4 (for steps of 2, 20, ...)
+    /// or 5 parts, time scales don't have minor ticks.
+    /// Logarithmic scales get ticks at the multiples of each power (e.g. 20, 30, ..., 90)
+    /// if every power has a major tick and ticks at the skipped powers otherwise.
+    pub fn minor_values(&self) -> Vec<f64> {
+        if self.interval.is_some() {
+            return vec![];
+        }
+        let Some(base) = self.scale.base() else {
+            let fraction = self.spacing / 10.0f64.powf(self.spacing.log10().floor());
+            let parts = if (fraction - 2.0).abs() < TOLERANCE {
+                4
+            } else {
+                5
+            };
+            let step = self.spacing / parts as f64;
+            let first = (self.min_point / step - TOLERANCE).ceil() as i64;
+            let last = (self.max_point / step + TOLERANCE).floor() as i64;
+            return (first..=last)
+                .filter(|i| i % parts != 0)
+                .map(|i| round_to_step(i as f64 * step, step))
+                .collect();
+        };
+        let powers = (self.min_point.log(base) + TOLERANCE).floor() as i32
+            ..(self.max_point.log(base) - TOLERANCE).ceil() as i32;
+        let within = |v: &f64| *v >= self.min_point && *v <= self.max_point;
+        if self.spacing > 1.0 {
+            let first = self.first_exponent(base) as i32;
+            let spacing = self.spacing as i32;
+            return powers
+                .filter(|p| (p - first).rem_euclid(spacing) != 0)
+                .map(|p| base.powi(p))
+                .filter(within)
+                .collect();
+        }
+        let multiples: &[f64] = if base == 2.0 {
+            &[1.5]
+        } else {
+            &[2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]
+        };
+        powers
+            .flat_map(|p| multiples.iter().map(move |m| m * base.powi(p)))
+            .filter(within)
+            .collect()
+    }
 }
 
 #[allow(clippy::collapsible_else_if)]
@@ -36,20 +302,169 @@ pub fn nice_num(num: f64, round: bool) -> f64 {
     nice_fraction * 10.0f64.powf(exponent)
 }
 
+/// Ticks on round numbers covering `min..max`, about `max_ticks` of them.
+///
+/// Works for any input, see [`sanitize_range`] for how empty, non-finite and single value
+/// ranges are handled.
 pub fn nice_ticks(min: f64, max: f64, max_ticks: u8) -> TickSpacing {
+    let (min, max) = sanitize_range(min, max);
     let range = nice_num(max - min, false);
-    let spacing = nice_num(range / (max_ticks - 1) as f64, true);
-    let min_point = (min / spacing).floor() * spacing;
-    let max_point = (max / spacing).ceil() * spacing;
-    let num_ticks = ((max_point - min_point) / spacing) as u8 + 1;
+    let mut spacing = nice_num(range / (max_ticks.max(2) - 1) as f64, true);
+    let steps = |spacing: f64| (max / spacing).ceil() - (min / spacing).floor();
+    // rounding to nice numbers can add a few ticks, which must still fit in a u8
+    while steps(spacing) >= u8::MAX as f64 {
+        spacing *= 2.0;
+    }
+    let min_point = round_to_step((min / spacing).floor() * spacing, spacing);
+    let max_point = round_to_step((max / spacing).ceil() * spacing, spacing);
     TickSpacing {
         min_point,
         max_point,
         spacing,
-        num_ticks,
+        num_ticks: steps(spacing) as u8 + 1,
+        scale: Scale::Linear,
+        interval: None,
+    }
+}
+
+/// Ticks on powers of `scale`'s base, at most `max_ticks` of them
+pub fn log_ticks(min: f64, max: f64, scale: Scale, max_ticks: u8) -> TickSpacing {
+    let base = scale.base().expect("a logarithmic scale");
+    let (min, max) = sanitize_log_range(min, max, base);
+    let min_exponent = min.log(base).floor();
+    let max_exponent = max.log(base).ceil().max(min_exponent + 1.0);
+    let spacing = ((max_exponent - min_exponent) / (max_ticks.max(2) - 1) as f64).ceil();
+    let num_ticks = ((max_exponent - min_exponent) / spacing).ceil();
+    TickSpacing {
+        min_point: base.powf(min_exponent),
+        max_point: base.powf(min_exponent + num_ticks * spacing),
+        spacing,
+        num_ticks: num_ticks as u8 + 1,
+        scale,
+        interval: None,
+    }
+}
+
+/// Range of values shown on an axis
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Domain {
+    /// fixed lower end of the axis, taken from the data if not set
+    pub min: Option<f64>,
+    /// fixed upper end of the axis, taken from the data if not set
+    pub max: Option<f64>,
+    /// extends the range taken from the data to zero, only on linear scales
+    pub include_zero: bool,
+    /// fraction of the data range that is added below and above it (in powers on
+    /// logarithmic scales), fixed ends aren't padded
+    pub padding: f64,
+    /// extends the ends taken from the data to the next tick, so that the axis starts and
+    /// ends with a tick. Fixed ends are always kept as they are
+    pub nice: bool,
+}
+
+impl Default for Domain {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            include_zero: false,
+            padding: 0.0,
+            nice: true,
+        }
+    }
+}
+
+impl Domain {
+    /// Axis from `min` to `max`, regardless of the data
+    pub fn fixed(min: f64, max: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+            ..Default::default()
+        }
+    }
+
+    /// Lower and upper end of the axis for data between `min` and `max`, before they're
+    /// extended to nice values
+    pub fn resolve(&self, scale: Scale, min: f64, max: f64) -> (f64, f64) {
+        let (min, max) = match (min.is_finite() && max.is_finite(), scale.base()) {
+            (true, _) => (min, max),
+            (false, None) => (0.0, 1.0),
+            (false, Some(base)) => (1.0, base),
+        };
+        let (min, max) = if self.include_zero && scale == Scale::Linear {
+            (min.min(0.0), max.max(0.0))
+        } else {
+            (min, max)
+        };
+        let (min, max) = match scale.base() {
+            None => {
+                let padding = (max - min) * self.padding;
+                (min - padding, max + padding)
+            }
+            Some(base) => {
+                let padding = (max / min).log(base) * self.padding;
+                (min / base.powf(padding), max * base.powf(padding))
+            }
+        };
+        let (min, max) = (self.min.unwrap_or(min), self.max.unwrap_or(max));
+        if max > min {
+            return (min, max);
+        }
+        // a single value, or fixed ends that don't fit the data
+        match scale.base() {
+            None => (min - 1.0, min + 1.0),
+            Some(base) => (min / base, min * base),
+        }
     }
 }
 
+/// Scale that can be used for `values`.
+///
+/// Logarithmic scales can't show zero or negative values, so they fall back to a linear
+/// scale (with a warning) if there are any.
+pub fn checked_scale(scale: Scale, values: &[f64]) -> Scale {
+    if scale.base().is_some() && values.iter().any(|v| *v <= 0.0) {
+        warn!(
+            "{:?} scale can't show values <= 0, falling back to a linear scale",
+            scale
+        );
+        return Scale::Linear;
+    }
+    scale
+}
+
+/// Ticks covering `values` on `scale`, within `domain`.
+///
+/// Non-finite values are ignored, as are non-positive values on logarithmic scales.
+pub fn scale_ticks(scale: Scale, domain: &Domain, values: &[f64], max_ticks: u8) -> TickSpacing {
+    let (min, max) = values
+        .iter()
+        .filter(|v| v.is_finite() && (scale.base().is_none() || **v > 0.0))
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), v| {
+            (f64::min(a, *v), f64::max(b, *v))
+        });
+    let (min, max) = domain.resolve(scale, min, max);
+    let (min, max) = match scale.base() {
+        None => sanitize_range(min, max),
+        Some(base) => sanitize_log_range(min, max, base),
+    };
+    let ticks = match scale {
+        Scale::Linear => nice_ticks(min, max, max_ticks),
+        Scale::Log10 | Scale::Log2 => log_ticks(min, max, scale, max_ticks),
+        Scale::Time => time::time_ticks(min, max, max_ticks),
+    };
+    let min = match domain.min {
+        None if domain.nice => ticks.min_point,
+        _ => min,
+    };
+    let max = match domain.max {
+        None if domain.nice => ticks.max_point,
+        _ => max,
+    };
+    ticks.within(min, max)
+}
+
 #[allow(clippy::ptr_arg)]
 pub fn get_min_max<T>(values: &Vec<T>) -> (f64, f64)
 where
@@ -67,21 +482,226 @@ where
     )
 }
 
-pub fn get_ticks(ticks: &TickSpacing) -> Vec<(f64, String)> {
-    (0..ticks.num_ticks)
-        .map(|i| ticks.min_point + i as f64 * ticks.spacing)
+/// Converts an unnamed series (named `label`, or "Series 1" without one) and multiple named
+/// series of (x, y) points to f64, the unnamed series comes first if it isn't empty
+pub fn get_xy_series<T, U>(
+    values: Vec<(T, U)>,
+    label: Option<String>,
+    series: Vec<NamedSeries<(T, U)>>,
+) -> Vec<NamedSeries<(f64, f64)>>
+where
+    T: ToPrimitive,
+    U: ToPrimitive,
+{
+    let to_f64 = |values: Vec<(T, U)>| {
+        values
+            .into_iter()
+            .map(|(x, y)| (x.to_f64().unwrap(), y.to_f64().unwrap()))
+            .collect::<Vec<(f64, f64)>>()
+    };
+    (!values.is_empty())
+        .then(|| {
+            NamedSeries::new(
+                label.unwrap_or_else(|| "Series 1".to_string()),
+                to_f64(values),
+            )
+        })
+        .into_iter()
+        .chain(
+            series
+                .into_iter()
+                .map(|s| NamedSeries::new(s.name, to_f64(s.values))),
+        )
+        .collect()
+}
+
+/// Minimum and maximum of x and y over all points of all series
+pub fn get_xy_min_max(series: &[NamedSeries<(f64, f64)>]) -> ((f64, f64), (f64, f64)) {
+    series.iter().flat_map(|s| s.values.iter()).fold(
+        (
+            (f64::INFINITY, f64::NEG_INFINITY),
+            (f64::INFINITY, f64::NEG_INFINITY),
+        ),
+        |((acc_min_x, acc_max_x), (acc_min_y, acc_max_y)), (x, y)| {
+            (
+                (f64::min(acc_min_x, *x), f64::max(acc_max_x, *x)),
+                (f64::min(acc_min_y, *y), f64::max(acc_max_y, *y)),
+            )
+        },
+    )
+}
+
+/// Stacks the values of several series on top of each other, per category.
+///
+/// Positive values stack upwards from zero and negative values downwards from zero.
+/// Returns the (start, end) of every value's segment, indexed like `series`. With
+/// `normalize`, each category is scaled so that its segments span 100 in total.
+pub fn stack_series(series: &[Vec<f64>], normalize: bool) -> Vec<Vec<(f64, f64)>> {
+    let num_categories = series.iter().map(Vec::len).max().unwrap_or(0);
+    let scales = (0..num_categories)
+        .map(|c| {
+            let total = series
+                .iter()
+                .filter_map(|s| s.get(c))
+                .map(|v| v.abs())
+                .sum::<f64>();
+            if normalize && total > 0.0 {
+                100.0 / total
+            } else {
+                1.0
+            }
+        })
+        .collect::<Vec<f64>>();
+    let mut positive = vec![0.0; num_categories];
+    let mut negative = vec![0.0; num_categories];
+    series
+        .iter()
+        .map(|s| {
+            s.iter()
+                .enumerate()
+                .map(|(c, v)| {
+                    let stack = if *v >= 0.0 {
+                        &mut positive[c]
+                    } else {
+                        &mut negative[c]
+                    };
+                    let start = *stack;
+                    *stack += v * scales[c];
+                    (start, *stack)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Like [`stack_series`], but series on the secondary axis (where `secondary[i]` is set) are
+/// stacked separately from the others
+pub fn stack_series_per_axis(
+    series: &[Vec<f64>],
+    secondary: &[bool],
+    normalize: bool,
+) -> Vec<Vec<(f64, f64)>> {
+    let mut spans = vec![vec![]; series.len()];
+    for on_secondary in [false, true] {
+        let (indices, values): (Vec<usize>, Vec<Vec<f64>>) = series
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| secondary[*i] == on_secondary)
+            .map(|(i, s)| (i, s.clone()))
+            .unzip();
+        for (i, s) in indices.into_iter().zip(stack_series(&values, normalize)) {
+            spans[i] = s;
+        }
+    }
+    spans
+}
+
+pub fn get_ticks(
+    ticks: &TickSpacing,
+    format: &dyn TickFormat,
+    locale: Locale,
+) -> Vec<(f64, String)> {
+    ticks
+        .values()
+        .into_iter()
         .map(move |tick| {
             (
-                100.0 - (tick - ticks.min_point) / (ticks.max_point - ticks.min_point) * 100.0,
-                format!("{}", tick),
+                100.0 - ticks.position(tick),
+                ticks.label(tick, format, locale),
             )
         })
         .collect::<Vec<(f64, String)>>()
 }
 
+/// Positions of the minor ticks in percent from the top.
+///
+/// Logarithmic scales always have minor ticks, linear scales only if `linear` is set.
+pub fn get_minor_ticks(ticks: &TickSpacing, linear: bool) -> Vec<f64> {
+    if !linear && !ticks.scale.is_log() {
+        return vec![];
+    }
+    ticks
+        .minor_values()
+        .into_iter()
+        .map(|tick| 100.0 - ticks.position(tick))
+        .collect()
+}
+
+/// Positions of the minor ticks in percent from the left, see [`get_minor_ticks`]
+pub fn get_x_minor_ticks(ticks: &TickSpacing, linear: bool) -> Vec<f64> {
+    if !linear && !ticks.scale.is_log() {
+        return vec![];
+    }
+    ticks
+        .minor_values()
+        .into_iter()
+        .map(|tick| ticks.position(tick))
+        .collect()
+}
+
+/// Ticks for a horizontal axis, positions are in percent from the left
+pub fn get_x_ticks(
+    ticks: &TickSpacing,
+    format: &dyn TickFormat,
+    locale: Locale,
+) -> Vec<(f64, String)> {
+    get_ticks(ticks, format, locale)
+        .into_iter()
+        .map(|(position, label)| (100.0 - position, label))
+        .collect()
+}
+
+/// Start and width of the `i`th of `n` bands (e.g. bars), in percent
+pub fn band(i: usize, n: usize) -> (f64, f64) {
+    let n = n.max(1) as f64;
+    (5.0 + 95.0 / n * i as f64, 80.0 / n)
+}
+
+/// Positions in percent between neighbouring bands of `n` bands, in the middle of their gaps,
+/// where gridlines of categorical axes go
+pub fn band_boundaries(n: usize) -> Vec<f64> {
+    (1..n)
+        .map(|i| {
+            let (start, _) = band(i, n);
+            start - 7.5 / n as f64
+        })
+        .collect()
+}
+
+/// Position in percent of `units` of `n` bands, with the center of the first band at 0, the
+/// second at 1 and so on
+pub fn band_position(units: f64, n: usize) -> f64 {
+    let n = n.max(1) as f64;
+    5.0 + 40.0 / n + units * 95.0 / n
+}
+
+/// Units of `n` bands at `position` in percent, the inverse of [`band_position`]
+pub fn band_units(position: f64, n: usize) -> f64 {
+    let n = n.max(1) as f64;
+    (position - 5.0 - 40.0 / n) * n / 95.0
+}
+
+/// Ticks at the centers of `n` bands for categorical axes, positions are in percent from the
+/// left. Categories without a label in `labels` get a tick with an empty label, extra labels
+/// are ignored
+pub fn get_band_ticks(labels: &[String], n: usize) -> Vec<(f64, String)> {
+    (0..n)
+        .map(|i| {
+            let (start, width) = band(i, n);
+            (
+                start + width / 2.0,
+                labels.get(i).cloned().unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::format::Plain;
+    use itertools::Itertools;
+    use proptest::prelude::*;
 
     #[test]
     fn min_max() {
@@ -103,7 +723,7 @@ mod tests {
         assert_eq!(ticks.spacing, 2.0);
         assert_eq!(ticks.num_ticks, 11);
 
-        let ticks = get_ticks(&ticks);
+        let ticks = get_ticks(&ticks, &Plain, Locale::default());
         assert_eq!(ticks[0].0, 100.0);
         assert_eq!(ticks[0].1, "-10");
         assert_eq!(ticks[4].0, 60.0);
@@ -111,4 +731,302 @@ mod tests {
         assert_eq!(ticks[10].0, 0.0);
         assert_eq!(ticks[10].1, "10");
     }
+
+    #[test]
+    fn values_at_positions() {
+        let ticks = nice_ticks(-10.0, 10.0, 10);
+        assert_eq!(ticks.value_at(ticks.position(4.0)), 4.0);
+        let ticks = log_ticks(1.0, 1000.0, Scale::Log10, 4);
+        assert_eq!(ticks.value_at(50.0).round(), 32.0);
+    }
+
+    #[test]
+    fn x_ticks() {
+        let ticks = get_x_ticks(&nice_ticks(0.0, 10.0, 3), &Plain, Locale::default());
+        assert_eq!(
+            ticks,
+            vec![
+                (0.0, "0".to_string()),
+                (50.0, "5".to_string()),
+                (100.0, "10".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn band_ticks() {
+        assert_eq!(band(0, 4), (5.0, 20.0));
+        assert_eq!(band(2, 4), (52.5, 20.0));
+        let labels = ["a".to_string(), "b".to_string()];
+        let ticks = get_band_ticks(&labels, 2);
+        assert_eq!(
+            ticks,
+            vec![(25.0, "a".to_string()), (72.5, "b".to_string())]
+        );
+        // the ticks follow the bars, not the labels
+        assert_eq!(get_band_ticks(&labels, 1), vec![(45.0, "a".to_string())]);
+        assert_eq!(
+            get_band_ticks(&labels[..1], 2),
+            vec![(25.0, "a".to_string()), (72.5, String::new())]
+        );
+        assert_eq!(band_boundaries(2), vec![48.75]);
+        // gridlines between the bars of 3 categories, with labels for only 2 of them
+        let ticks = get_band_ticks(&labels, 3);
+        let boundaries = band_boundaries(3);
+        assert_eq!(boundaries.len(), 2);
+        assert!(ticks[0].0 < boundaries[0] && boundaries[0] < ticks[1].0);
+        assert!(ticks[1].0 < boundaries[1] && boundaries[1] < ticks[2].0);
+        assert!(band_boundaries(1).is_empty());
+        assert_eq!(band_position(1.0, 2), 72.5);
+        assert_eq!(band_units(25.0, 2), 0.0);
+    }
+
+    #[test]
+    fn stacking() {
+        let series = vec![vec![1.0, -2.0, 3.0], vec![2.0, -1.0], vec![-1.0, 1.0, 1.0]];
+        assert_eq!(
+            stack_series(&series, false),
+            vec![
+                vec![(0.0, 1.0), (0.0, -2.0), (0.0, 3.0)],
+                vec![(1.0, 3.0), (-2.0, -3.0)],
+                vec![(0.0, -1.0), (0.0, 1.0), (3.0, 4.0)],
+            ]
+        );
+        assert_eq!(
+            stack_series(&series, true),
+            vec![
+                vec![(0.0, 25.0), (0.0, -50.0), (0.0, 75.0)],
+                vec![(25.0, 75.0), (-50.0, -75.0)],
+                vec![(0.0, -25.0), (0.0, 25.0), (75.0, 100.0)],
+            ]
+        );
+        assert_eq!(
+            stack_series_per_axis(&series, &[false, true, false], false),
+            vec![
+                vec![(0.0, 1.0), (0.0, -2.0), (0.0, 3.0)],
+                vec![(0.0, 2.0), (0.0, -1.0)],
+                vec![(0.0, -1.0), (0.0, 1.0), (3.0, 4.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn xy_min_max() {
+        let series = get_xy_series(
+            vec![(1, 2.5), (3, -1.0)],
+            None,
+            vec![NamedSeries::new("b", vec![(-2, 4.0)])],
+        );
+        assert_eq!(series[0].name, "Series 1");
+        assert_eq!(series[1].values, vec![(-2.0, 4.0)]);
+        assert_eq!(get_xy_min_max(&series), ((-2.0, 3.0), (-1.0, 4.0)));
+    }
+
+    #[test]
+    fn log_scale() {
+        let ticks = log_ticks(3.0, 4200.0, Scale::Log10, 10);
+        assert_eq!(ticks.min_point, 1.0);
+        assert_eq!(ticks.max_point, 10000.0);
+        assert_eq!(ticks.values(), vec![1.0, 10.0, 100.0, 1000.0, 10000.0]);
+        assert_eq!(ticks.position(100.0), 50.0);
+        assert_eq!(ticks.position(0.5), 0.0);
+        assert_eq!(ticks.minor_values().len(), 4 * 8);
+        assert_eq!(ticks.minor_values()[0], 2.0);
+
+        let ticks = log_ticks(1.0, 1e8, Scale::Log10, 5);
+        assert_eq!(ticks.spacing, 2.0);
+        assert_eq!(ticks.values(), vec![1.0, 1e2, 1e4, 1e6, 1e8]);
+        assert_eq!(ticks.minor_values(), vec![1e1, 1e3, 1e5, 1e7]);
+
+        let ticks = log_ticks(3.0, 100.0, Scale::Log2, 10);
+        assert_eq!(ticks.min_point, 2.0);
+        assert_eq!(ticks.max_point, 128.0);
+        assert_eq!(ticks.minor_values()[0], 3.0);
+
+        let ticks = get_ticks(&log_ticks(1.0, 100.0, Scale::Log10, 5), &Plain, Locale::DE);
+        assert_eq!(ticks[1], (50.0, "10".to_string()));
+    }
+
+    #[test]
+    fn log_scale_fallback() {
+        assert_eq!(checked_scale(Scale::Log10, &[1.0, 10.0]), Scale::Log10);
+        assert_eq!(checked_scale(Scale::Log2, &[1.0, 0.0]), Scale::Linear);
+        assert_eq!(
+            scale_ticks(Scale::Log10, &Domain::default(), &[0.0, 5.0, 50.0], 5),
+            log_ticks(5.0, 50.0, Scale::Log10, 5)
+        );
+        assert_eq!(
+            scale_ticks(
+                Scale::Linear,
+                &Domain::default(),
+                &[0.0, 4.0, f64::NEG_INFINITY],
+                5
+            ),
+            nice_ticks(0.0, 4.0, 5)
+        );
+    }
+
+    #[test]
+    fn domains() {
+        let temperatures = [20.3, 24.6, 22.0];
+        let ticks = scale_ticks(Scale::Linear, &Domain::default(), &temperatures, 5);
+        assert_eq!((ticks.min_point, ticks.max_point), (20.0, 25.0));
+
+        let with_zero = Domain {
+            include_zero: true,
+            ..Default::default()
+        };
+        let ticks = scale_ticks(Scale::Linear, &with_zero, &temperatures, 5);
+        assert_eq!((ticks.min_point, ticks.max_point), (0.0, 30.0));
+
+        let exact = Domain {
+            nice: false,
+            padding: 0.1,
+            ..Default::default()
+        };
+        assert_eq!(exact.resolve(Scale::Linear, 20.0, 25.0), (19.5, 25.5));
+        let ticks = scale_ticks(Scale::Linear, &exact, &[20.0, 25.0], 5);
+        assert_eq!((ticks.min_point, ticks.max_point), (19.5, 25.5));
+        assert_eq!(ticks.values(), vec![20.0, 22.0, 24.0]);
+
+        let ticks = scale_ticks(Scale::Linear, &Domain::fixed(-5.0, 5.0), &temperatures, 5);
+        assert_eq!((ticks.min_point, ticks.max_point), (-5.0, 5.0));
+        assert_eq!(ticks.position(0.0), 50.0);
+        // lines below the fixed minimum are clipped instead of drawn on the bottom
+        assert_eq!(ticks.point_position(-8.0), -30.0);
+        assert_eq!(ticks.point_position(f64::NEG_INFINITY), 0.0);
+
+        assert_eq!(
+            Domain::default().resolve(Scale::Linear, 3.0, 3.0),
+            (2.0, 4.0)
+        );
+        assert_eq!(
+            Domain::default().resolve(Scale::Log10, 3.0, 3.0),
+            (0.3, 30.0)
+        );
+        let ticks = scale_ticks(Scale::Log10, &Domain::fixed(5.0, 5000.0), &[], 5);
+        assert_eq!(ticks.values(), vec![10.0, 100.0, 1000.0]);
+        assert_eq!(ticks.minor_values()[0], 5.0);
+        assert!(ticks.point_position(0.5) < 0.0);
+        assert_eq!(ticks.point_position(0.0), 0.0);
+    }
+
+    #[test]
+    fn linear_minor_ticks() {
+        let ticks = nice_ticks(0.0, 10.0, 3);
+        assert_eq!(ticks.spacing, 5.0);
+        assert_eq!(
+            ticks.minor_values(),
+            vec![1.0, 2.0, 3.0, 4.0, 6.0, 7.0, 8.0, 9.0]
+        );
+        let ticks = nice_ticks(-2.0, 2.0, 3);
+        assert_eq!(ticks.spacing, 2.0);
+        assert_eq!(ticks.minor_values(), vec![-1.5, -1.0, -0.5, 0.5, 1.0, 1.5]);
+        assert_eq!(get_minor_ticks(&ticks, true)[0], 87.5);
+        assert_eq!(get_minor_ticks(&ticks, false), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn degenerate_ticks() {
+        let ticks = nice_ticks(f64::INFINITY, f64::NEG_INFINITY, 5);
+        assert_eq!((ticks.min_point, ticks.max_point), (0.0, 1.0));
+        let ticks = nice_ticks(f64::NAN, 7.0, 5);
+        assert_eq!((ticks.min_point, ticks.max_point), (6.0, 8.0));
+        let ticks = nice_ticks(1e20, 1e20, 5);
+        assert_eq!((ticks.min_point, ticks.max_point), (9e19, 1.1e20));
+        let ticks = nice_ticks(10.0, 0.0, 0);
+        assert_eq!((ticks.min_point, ticks.max_point), (0.0, 10.0));
+        assert_eq!(nice_ticks(0.1, 0.7, 11).values()[2], 0.3);
+        assert_eq!(nice_ticks(-1e308, 1e308, 5).max_point, 1e300);
+        let ticks = nice_ticks(0.0, 1.0, u8::MAX);
+        assert!(ticks.num_ticks < u8::MAX);
+        assert_eq!(ticks.values()[3], 0.015);
+
+        let ticks = log_ticks(-5.0, 0.0, Scale::Log10, 5);
+        assert_eq!(ticks.values(), vec![1.0, 10.0]);
+        let ticks = log_ticks(f64::NAN, 100.0, Scale::Log10, 5);
+        assert_eq!(ticks.values(), vec![10.0, 100.0, 1000.0]);
+
+        let ticks = crate::time::time_ticks(f64::NAN, f64::INFINITY, 5);
+        assert_eq!(ticks.values(), vec![0.0, 1.0]);
+        let ticks = scale_ticks(Scale::Linear, &Domain::fixed(f64::NAN, 1.0), &[], 5);
+        assert!(ticks.min_point.is_finite());
+    }
+
+    fn assert_sane(ticks: &TickSpacing) {
+        assert!(ticks.min_point.is_finite() && ticks.max_point.is_finite());
+        assert!(ticks.min_point < ticks.max_point);
+        assert!(ticks.spacing > 0.0 && ticks.spacing.is_finite());
+        assert!(ticks.num_ticks >= 1);
+        let values = ticks.values();
+        assert!(values.iter().tuple_windows().all(|(a, b)| a < b));
+        for v in values {
+            let position = ticks.position(v);
+            assert!((-1e-6..=100.0 + 1e-6).contains(&position), "{}", position);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn linear_ticks_for_any_input(min in any::<f64>(), max in any::<f64>(), max_ticks in any::<u8>()) {
+            let ticks = nice_ticks(min, max, max_ticks);
+            assert_sane(&ticks);
+            let n = max_ticks.max(2) as usize;
+            prop_assert!(ticks.num_ticks as usize <= 3 * (n - 1) / 2 + 3);
+        }
+
+        #[test]
+        fn linear_ticks_cover_data(min in -1e300..1e300, max in -1e300..1e300, max_ticks in 2u8..) {
+            let ticks = nice_ticks(min, max, max_ticks);
+            prop_assert!(ticks.min_point <= min.min(max));
+            prop_assert!(ticks.max_point >= min.max(max));
+        }
+
+        #[test]
+        fn linear_ticks_are_rounded(min in -1e6..1e6, width in 1e-3..1e6, max_ticks in 2u8..20) {
+            let ticks = nice_ticks(min, min + width, max_ticks);
+            let decimals = (1.0 - ticks.spacing.log10().floor()).max(0.0) as usize;
+            for v in ticks.values().into_iter().chain(ticks.minor_values()) {
+                let label = v.to_string();
+                let fraction = label.split_once('.').map_or(0, |(_, f)| f.len());
+                prop_assert!(fraction <= decimals, "{} with spacing {}", label, ticks.spacing);
+            }
+        }
+
+        #[test]
+        fn log_ticks_for_any_input(min in any::<f64>(), max in any::<f64>(), max_ticks in any::<u8>(), log2 in any::<bool>()) {
+            let scale = if log2 { Scale::Log2 } else { Scale::Log10 };
+            let ticks = log_ticks(min, max, scale, max_ticks);
+            assert_sane(&ticks);
+            prop_assert!(ticks.min_point > 0.0);
+            prop_assert!(ticks.num_ticks <= max_ticks.max(2));
+        }
+
+        #[test]
+        fn time_ticks_for_any_input(min in any::<f64>(), max in any::<f64>(), max_ticks in any::<u8>()) {
+            let ticks = crate::time::time_ticks(min, max, max_ticks);
+            assert_sane(&ticks);
+            for v in ticks.values() {
+                ticks.label(v, &Plain, Locale::default());
+            }
+        }
+
+        #[test]
+        fn domains_for_any_input(
+            values in prop::collection::vec(any::<f64>(), 0..5),
+            min in prop::option::of(any::<f64>()),
+            max in prop::option::of(any::<f64>()),
+            padding in 0.0..1.0,
+            nice in any::<bool>(),
+        ) {
+            let domain = Domain { min, max, include_zero: false, padding, nice };
+            for scale in [Scale::Linear, Scale::Log10, Scale::Time] {
+                let ticks = scale_ticks(scale, &domain, &values, 5);
+                prop_assert!(ticks.min_point.is_finite() && ticks.max_point.is_finite());
+                for v in ticks.values() {
+                    prop_assert!(ticks.position(v).is_finite());
+                }
+            }
+        }
+    }
 }
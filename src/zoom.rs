@@ -0,0 +1,252 @@
+use leptos::{ev, svg::Rect, *};
+use leptos_use::use_event_listener;
+
+use crate::{layout::PlotArea, utils::Scale};
+
+/// Visible part of a zoomable chart, in data coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Viewport {
+    pub x: (f64, f64),
+    /// visible range of the y axis, the whole axis if not set
+    pub y: Option<(f64, f64)>,
+}
+
+/// Converts values on `scale` to positions along a linear axis
+fn to_linear(scale: Scale, value: f64) -> f64 {
+    match scale.base() {
+        Some(base) => value.log(base),
+        None => value,
+    }
+}
+
+fn from_linear(scale: Scale, value: f64) -> f64 {
+    match scale.base() {
+        Some(base) => base.powf(value),
+        None => value,
+    }
+}
+
+/// `range` zoomed by `factor` around `center`, a fraction of the range from its start
+fn zoom_range(range: (f64, f64), scale: Scale, center: f64, factor: f64) -> (f64, f64) {
+    let (min, max) = (to_linear(scale, range.0), to_linear(scale, range.1));
+    let center = min + (max - min) * center;
+    (
+        from_linear(scale, center - (center - min) * factor),
+        from_linear(scale, center + (max - center) * factor),
+    )
+}
+
+/// `range` moved by `shift`, a fraction of its length
+fn pan_range(range: (f64, f64), scale: Scale, shift: f64) -> (f64, f64) {
+    let (min, max) = (to_linear(scale, range.0), to_linear(scale, range.1));
+    let shift = (max - min) * shift;
+    (
+        from_linear(scale, min + shift),
+        from_linear(scale, max + shift),
+    )
+}
+
+impl Viewport {
+    /// Zooms in (for a `factor` below 1) or out around `center`, given as fractions of the
+    /// viewport from its bottom left corner. `scales` are the scales of the x and y axis.
+    pub fn zoom(self, scales: (Scale, Scale), center: (f64, f64), factor: f64) -> Self {
+        Self {
+            x: zoom_range(self.x, scales.0, center.0, factor),
+            y: self.y.map(|y| zoom_range(y, scales.1, center.1, factor)),
+        }
+    }
+
+    /// Moves the viewport by `shift`, in fractions of its width and height
+    pub fn pan(self, scales: (Scale, Scale), shift: (f64, f64)) -> Self {
+        Self {
+            x: pan_range(self.x, scales.0, shift.0),
+            y: self.y.map(|y| pan_range(y, scales.1, shift.1)),
+        }
+    }
+
+    /// Whether all of `other` is visible, ignoring y if either isn't limited vertically
+    pub fn contains(&self, other: &Viewport) -> bool {
+        let covers = |a: (f64, f64), b: (f64, f64)| a.0 <= b.0 && a.1 >= b.1;
+        covers(self.x, other.x)
+            && match (self.y, other.y) {
+                (Some(y), Some(other)) => covers(y, other),
+                _ => true,
+            }
+    }
+}
+
+/// Interactions that change the [`Viewport`] of a chart
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomOptions {
+    /// zooms and pans the y axis as well, otherwise only the x axis.
+    /// Series on a secondary axis always keep their whole range
+    pub y: bool,
+    /// shows a control in the corner of the plot that resets the zoom
+    pub reset_button: bool,
+}
+
+impl Default for ZoomOptions {
+    fn default() -> Self {
+        Self {
+            y: false,
+            reset_button: true,
+        }
+    }
+}
+
+/// Zooms `viewport` with the mouse wheel and pinch gestures on `target`, and pans it by
+/// dragging if `pan` is set.
+///
+/// `full` is the viewport that shows all data, zooming out beyond it resets `viewport` to
+/// `None`. `scales` are the scales of the x and y axis.
+pub(crate) fn use_zoom(
+    target: NodeRef<Rect>,
+    viewport: RwSignal<Option<Viewport>>,
+    full: Signal<Viewport>,
+    scales: Signal<(Scale, Scale)>,
+    options: ZoomOptions,
+    pan: bool,
+) {
+    // ids and client positions of the pointers that are down
+    let pointers = store_value(Vec::<(i32, (f64, f64))>::new());
+    let current = move || {
+        let full = full.get_untracked();
+        let current = viewport.get_untracked().unwrap_or(full);
+        Viewport {
+            x: current.x,
+            y: options.y.then(|| current.y.or(full.y)).flatten(),
+        }
+    };
+    let update = move |new: Viewport| {
+        if new.contains(&full.get_untracked()) {
+            viewport.set(None);
+        } else {
+            viewport.set(Some(new));
+        }
+    };
+    // position in fractions of the plot from its bottom left corner
+    let fraction = move |(x, y): (f64, f64)| {
+        target.get_untracked().map(|el| {
+            let rect = el.get_bounding_client_rect();
+            (
+                (x - rect.left()) / rect.width(),
+                1.0 - (y - rect.top()) / rect.height(),
+            )
+        })
+    };
+
+    let _ = use_event_listener(target, ev::wheel, move |e| {
+        e.prevent_default();
+        let Some(center) = fraction((e.client_x() as f64, e.client_y() as f64)) else {
+            return;
+        };
+        // lines are roughly 16px
+        let delta = match e.delta_mode() {
+            0 => e.delta_y(),
+            _ => e.delta_y() * 16.0,
+        };
+        update(current().zoom(scales.get_untracked(), center, (delta * 0.002).exp()));
+    });
+    let _ = use_event_listener(target, ev::pointerdown, move |e| {
+        if e.button() != 0 {
+            return;
+        }
+        // keep panning while the pointer is outside of the plot
+        if let Some(el) = target.get_untracked() {
+            let _ = el.set_pointer_capture(e.pointer_id());
+        }
+        pointers.update_value(|p| {
+            p.push((e.pointer_id(), (e.client_x() as f64, e.client_y() as f64)));
+        });
+    });
+    let _ = use_event_listener(target, ev::pointermove, move |e| {
+        let position = (e.client_x() as f64, e.client_y() as f64);
+        let previous = pointers.get_value();
+        let Some(i) = previous.iter().position(|(id, _)| *id == e.pointer_id()) else {
+            return;
+        };
+        let mut moved = previous.clone();
+        moved[i].1 = position;
+        pointers.set_value(moved.clone());
+        let (Some(from), Some(to)) = (fraction(previous[i].1), fraction(position)) else {
+            return;
+        };
+        match (&previous[..], &moved[..]) {
+            ([_], _) if pan => {
+                update(current().pan(scales.get_untracked(), (from.0 - to.0, from.1 - to.1)))
+            }
+            ([(_, a), (_, b), ..], [(_, c), (_, d), ..]) => {
+                let distance = |a: &(f64, f64), b: &(f64, f64)| (a.0 - b.0).hypot(a.1 - b.1);
+                let (before, after) = (distance(a, b), distance(c, d));
+                let Some(center) = fraction(((c.0 + d.0) / 2.0, (c.1 + d.1) / 2.0)) else {
+                    return;
+                };
+                if before > 0.0 && after > 0.0 {
+                    update(current().zoom(scales.get_untracked(), center, before / after));
+                }
+            }
+            _ => {}
+        }
+    });
+    let release = move |id: i32| pointers.update_value(|p| p.retain(|(p, _)| *p != id));
+    let _ = use_event_listener(target, ev::pointerup, move |e| release(e.pointer_id()));
+    let _ = use_event_listener(target, ev::pointercancel, move |e| release(e.pointer_id()));
+}
+
+/// Control in the top right corner of `area` that resets `viewport`, shown while zoomed
+#[component]
+pub fn ZoomReset(
+    viewport: RwSignal<Option<Viewport>>,
+    #[prop(into)] area: Signal<PlotArea>,
+) -> impl IntoView {
+    view! {
+        <Show when=move || viewport.with(Option::is_some) fallback=|| ()>
+            <text
+                x=move || format!("{}%", area.get().right())
+                y=move || format!("{}%", area.get().y)
+                dx="-6"
+                dy="6"
+                text-anchor="end"
+                dominant-baseline="hanging"
+                font-size="12px"
+                fill="#444"
+                text-decoration="underline"
+                cursor="pointer"
+                on:click=move |_| viewport.set(None)
+            >
+                "Reset zoom"
+            </text>
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zoom_and_pan() {
+        let scales = (Scale::Linear, Scale::Log10);
+        let rounded = |v: Option<(f64, f64)>| v.map(|(a, b)| (a.round(), b.round()));
+        let viewport = Viewport {
+            x: (0.0, 100.0),
+            y: Some((1.0, 1e4)),
+        };
+        let zoomed = viewport.zoom(scales, (0.25, 0.5), 0.5);
+        assert_eq!(zoomed.x, (12.5, 62.5));
+        assert_eq!(rounded(zoomed.y), Some((10.0, 1e3)));
+        assert!(viewport.contains(&zoomed));
+        assert!(!zoomed.contains(&viewport));
+
+        let panned = zoomed.pan(scales, (0.5, -0.5));
+        assert_eq!(panned.x, (37.5, 87.5));
+        assert_eq!(rounded(panned.y), Some((1.0, 100.0)));
+
+        // viewports that don't limit y only compare x
+        let wide = Viewport {
+            x: (-10.0, 110.0),
+            y: None,
+        };
+        assert!(wide.contains(&viewport));
+    }
+}